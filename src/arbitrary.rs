@@ -0,0 +1,45 @@
+//! Generates endian values for fuzzing from arbitrary native values.
+
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{Endian, Integer, Order};
+
+impl<'a, T: Endian<T> + Arbitrary<'a>, O: Order> Arbitrary<'a> for Integer<T, O> {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        T::arbitrary(u).map(Self::new)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        T::size_hint(depth)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ::arbitrary::{Arbitrary, Unstructured};
+
+    use crate::{BigEndian, LittleEndian};
+
+    #[test]
+    fn arbitrary() {
+        let bytes = [0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc];
+        let mut u = Unstructured::new(&bytes);
+
+        let a = BigEndian::<u32>::arbitrary(&mut u).unwrap();
+        let b = LittleEndian::<u16>::arbitrary(&mut u).unwrap();
+        assert!(u.is_empty());
+
+        // The native values are taken from the bytes the same way as those of
+        // the primitives.
+        let mut u = Unstructured::new(&bytes);
+        assert_eq!(a.to_native(), u32::arbitrary(&mut u).unwrap());
+        assert_eq!(b.to_native(), u16::arbitrary(&mut u).unwrap());
+
+        assert_eq!(
+            BigEndian::<u64>::size_hint(0),
+            <u64 as Arbitrary>::size_hint(0)
+        );
+    }
+}
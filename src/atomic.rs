@@ -0,0 +1,204 @@
+//! Atomic variants of the endian types, for fields that are shared between
+//! threads (or with hardware) and have to be stored in a specific byte order.
+
+use core::fmt;
+use core::sync::atomic::Ordering;
+
+use crate::Endian;
+
+/// Integer types that have a matching atomic type.
+pub trait AtomicInteger: Endian<Self> {
+    /// The atomic type with the same size as `Self`.
+    type Atomic;
+}
+
+/// An atomic integer stored in big endian.
+///
+/// All of the operations take and return native values.
+#[repr(transparent)]
+pub struct AtomicBigEndian<T: AtomicInteger>(T::Atomic);
+
+/// An atomic integer stored in little endian.
+///
+/// All of the operations take and return native values.
+#[repr(transparent)]
+pub struct AtomicLittleEndian<T: AtomicInteger>(T::Atomic);
+
+// Returns the strongest ordering that is valid for the failure case of a
+// compare exchange with `order` on success.
+fn failure_ordering(order: Ordering) -> Ordering {
+    match order {
+        Ordering::Release | Ordering::Relaxed => Ordering::Relaxed,
+        Ordering::Acquire | Ordering::AcqRel => Ordering::Acquire,
+        _ => Ordering::SeqCst,
+    }
+}
+
+macro_rules! impl_atomic {
+    ($($int_type:ident => $atomic_type:ident, $width:literal);+) => {
+        $(
+            #[cfg(target_has_atomic = $width)]
+            impl AtomicInteger for $int_type {
+                type Atomic = core::sync::atomic::$atomic_type;
+            }
+
+            impl_atomic!(@make_impl $int_type, $width => AtomicBigEndian, to_be, from_be, "big");
+            impl_atomic!(@make_impl $int_type, $width => AtomicLittleEndian, to_le, from_le, "little");
+        )+
+    };
+
+    (@make_impl $int_type:ident, $width:literal => $type:ident, $to:ident, $from:ident, $endian:literal) => {
+        #[cfg(target_has_atomic = $width)]
+        impl $type<$int_type> {
+            #[inline]
+            #[must_use]
+            pub const fn new(value: $int_type) -> Self {
+                Self(<$int_type as AtomicInteger>::Atomic::new(value.$to()))
+            }
+
+            /// Loads the value and converts it to the target’s endianness.
+            #[inline]
+            pub fn load(&self, order: Ordering) -> $int_type {
+                <$int_type>::$from(self.0.load(order))
+            }
+
+            /// Converts `value` to the byte order of `self` and stores it.
+            #[inline]
+            pub fn store(&self, value: $int_type, order: Ordering) {
+                self.0.store(value.$to(), order);
+            }
+
+            /// Adds `value` to the current value (wrapping around on overflow),
+            /// returning the previous value.
+            ///
+            /// If the byte order of `self` is not the target’s, the addition has
+            /// to be performed on the native value inside of a compare exchange
+            /// loop.
+            #[inline]
+            pub fn fetch_add(&self, value: $int_type, order: Ordering) -> $int_type {
+                if cfg!(target_endian = $endian) {
+                    return self.0.fetch_add(value, order);
+                }
+
+                let previous = self.0.fetch_update(order, failure_ordering(order), |bits| {
+                    Some(<$int_type>::$from(bits).wrapping_add(value).$to())
+                });
+
+                // The closure always returns `Some`, so the update cannot fail.
+                match previous {
+                    Ok(bits) | Err(bits) => <$int_type>::$from(bits),
+                }
+            }
+
+            /// Bitwise "or" of `value` with the current value, returning the
+            /// previous value.
+            ///
+            /// Bitwise operations do not depend on the byte order, so this is
+            /// performed on the stored bits directly.
+            #[inline]
+            pub fn fetch_or(&self, value: $int_type, order: Ordering) -> $int_type {
+                <$int_type>::$from(self.0.fetch_or(value.$to(), order))
+            }
+
+            /// Bitwise "and" of `value` with the current value, returning the
+            /// previous value.
+            ///
+            /// Same as `fetch_or`, this is performed on the stored bits directly.
+            #[inline]
+            pub fn fetch_and(&self, value: $int_type, order: Ordering) -> $int_type {
+                <$int_type>::$from(self.0.fetch_and(value.$to(), order))
+            }
+
+            /// Bitwise "xor" of `value` with the current value, returning the
+            /// previous value.
+            ///
+            /// Same as `fetch_or`, this is performed on the stored bits directly.
+            #[inline]
+            pub fn fetch_xor(&self, value: $int_type, order: Ordering) -> $int_type {
+                <$int_type>::$from(self.0.fetch_xor(value.$to(), order))
+            }
+        }
+
+        #[cfg(target_has_atomic = $width)]
+        impl fmt::Debug for $type<$int_type> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($type))
+                    .field(&self.load(Ordering::Relaxed))
+                    .finish()
+            }
+        }
+    };
+}
+
+impl_atomic!(
+    u8 => AtomicU8, "8";
+    u16 => AtomicU16, "16";
+    u32 => AtomicU32, "32";
+    u64 => AtomicU64, "64";
+    usize => AtomicUsize, "ptr";
+    i8 => AtomicI8, "8";
+    i16 => AtomicI16, "16";
+    i32 => AtomicI32, "32";
+    i64 => AtomicI64, "64";
+    isize => AtomicIsize, "ptr"
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn load_store() {
+        let value = AtomicBigEndian::<u32>::new(0x1234_5678);
+        assert_eq!(value.load(Ordering::SeqCst), 0x1234_5678);
+
+        value.store(0xcafe_babe, Ordering::SeqCst);
+        assert_eq!(value.load(Ordering::SeqCst), 0xcafe_babe);
+        assert_eq!(
+            value.0.load(Ordering::SeqCst).to_ne_bytes(),
+            [0xca, 0xfe, 0xba, 0xbe]
+        );
+
+        let value = AtomicLittleEndian::<i16>::new(-2);
+        assert_eq!(value.load(Ordering::Relaxed), -2);
+        assert_eq!(value.0.load(Ordering::Relaxed).to_ne_bytes(), [0xfe, 0xff]);
+    }
+
+    #[test]
+    fn fetch_add() {
+        let value = AtomicBigEndian::<u32>::new(0xff);
+        assert_eq!(value.fetch_add(1, Ordering::SeqCst), 0xff);
+        assert_eq!(value.fetch_add(0x100, Ordering::AcqRel), 0x100);
+        assert_eq!(value.load(Ordering::SeqCst), 0x200);
+
+        let value = AtomicLittleEndian::<u16>::new(0x00ff);
+        assert_eq!(value.fetch_add(1, Ordering::Release), 0x00ff);
+        assert_eq!(value.load(Ordering::Acquire), 0x0100);
+
+        let value = AtomicBigEndian::<u8>::new(u8::MAX);
+        assert_eq!(value.fetch_add(2, Ordering::Relaxed), u8::MAX);
+        assert_eq!(value.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn fetch_bitwise() {
+        let value = AtomicBigEndian::<u32>::new(0x1234_0000);
+        assert_eq!(value.fetch_or(0x0000_5678, Ordering::SeqCst), 0x1234_0000);
+        assert_eq!(value.load(Ordering::SeqCst), 0x1234_5678);
+        assert_eq!(
+            value.0.load(Ordering::SeqCst).to_ne_bytes(),
+            [0x12, 0x34, 0x56, 0x78]
+        );
+
+        assert_eq!(value.fetch_and(0xff00_ff00, Ordering::AcqRel), 0x1234_5678);
+        assert_eq!(value.load(Ordering::SeqCst), 0x1200_5600);
+
+        assert_eq!(value.fetch_xor(0x1200_00ff, Ordering::Relaxed), 0x1200_5600);
+        assert_eq!(value.load(Ordering::SeqCst), 0x0000_56ff);
+
+        let value = AtomicLittleEndian::<i16>::new(-1);
+        assert_eq!(value.fetch_and(0x00ff, Ordering::SeqCst), -1);
+        assert_eq!(value.load(Ordering::SeqCst), 0x00ff);
+        assert_eq!(value.0.load(Ordering::SeqCst).to_ne_bytes(), [0xff, 0x00]);
+    }
+}
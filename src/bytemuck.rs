@@ -0,0 +1,43 @@
+//! The endian types are `#[repr(transparent)]` wrappers, so they are plain old
+//! data whenever the wrapped type is.
+
+use ::bytemuck::{Pod, Zeroable};
+
+use crate::{Endian, Integer, Order};
+
+// SAFETY: `Integer<T, O>` is `#[repr(transparent)]` over `T`, and the all-zero
+// bit pattern of `T` is stored as is.
+unsafe impl<T: Endian<T> + Zeroable, O: Order> Zeroable for Integer<T, O> {}
+
+// SAFETY: `Integer<T, O>` is `#[repr(transparent)]` over `T` so it has the same
+// layout, and any bit pattern of `T` is a valid `Integer<T, O>`.
+unsafe impl<T: Endian<T> + Pod, O: Order> Pod for Integer<T, O> {}
+
+#[cfg(test)]
+mod test {
+    use crate::{BigEndian, LittleEndian};
+
+    #[test]
+    fn cast() {
+        let bytes = [0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0];
+
+        let values: [BigEndian<u32>; 2] = ::bytemuck::cast(bytes);
+        assert_eq!(values[0].to_native(), 0x1234_5678);
+        assert_eq!(values[1].to_native(), 0x9abc_def0);
+
+        let values: [LittleEndian<u32>; 2] = ::bytemuck::cast(bytes);
+        assert_eq!(values[0].to_native(), 0x7856_3412);
+        assert_eq!(values[1].to_native(), 0xf0de_bc9a);
+
+        let values: &[BigEndian<u16>] = ::bytemuck::cast_slice(&values);
+        assert_eq!(values.len(), 4);
+        assert_eq!(values[0].to_native(), 0x1234);
+        assert_eq!(::bytemuck::cast_slice::<_, u8>(values), bytes);
+    }
+
+    #[test]
+    fn zeroed() {
+        let value: BigEndian<u64> = ::bytemuck::Zeroable::zeroed();
+        assert_eq!(value.to_native(), 0);
+    }
+}
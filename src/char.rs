@@ -0,0 +1,157 @@
+//! Unicode scalar values stored in a specific byte order, e.g. for UTF-32
+//! encoded text.
+
+use core::char::CharTryFromError;
+use core::fmt;
+
+use crate::{Be, Integer, Le, Ne, Order};
+
+/// A `char` stored in the byte order `O`.
+///
+/// `char` does not implement [`Endian`](crate::Endian), as swapping the bytes
+/// of a valid `char` usually does not result in a valid `char`. Instead, the
+/// scalar value is stored as a `u32` and only the native value is ever exposed
+/// as a `char`.
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Char<O: Order>(Integer<u32, O>);
+
+/// A `char` stored in big endian.
+pub type BigEndianChar = Char<Be>;
+
+/// A `char` stored in little endian.
+pub type LittleEndianChar = Char<Le>;
+
+/// A `char` stored in the target’s byte order.
+pub type NativeEndianChar = Char<Ne>;
+
+impl<O: Order> Char<O> {
+    /// Creates a new value from `value` in the target’s endianness.
+    #[inline]
+    #[must_use]
+    pub fn new(value: char) -> Self {
+        Self(Integer::new(u32::from(value)))
+    }
+
+    /// Returns the value in the target’s endianness.
+    #[inline]
+    #[must_use]
+    pub fn to_native(self) -> char {
+        // SAFETY: The stored value is always created from a valid `char`.
+        unsafe { char::from_u32_unchecked(self.0.to_native()) }
+    }
+
+    /// Returns the stored scalar value, which is in the byte order of `self`.
+    ///
+    /// This is a `u32` rather than a `char`, as it is usually not a valid
+    /// `char` unless the byte order of `self` is the target’s.
+    #[inline]
+    #[must_use]
+    pub const fn to_bits(self) -> u32 {
+        self.0.to_bits()
+    }
+
+    /// Creates a new value from the stored scalar value `bits`, which is
+    /// expected to be in the byte order of `Self`.
+    ///
+    /// Returns `None` if `bits` is not a valid `char` once converted to the
+    /// target’s endianness.
+    #[inline]
+    #[must_use]
+    pub fn from_bits(bits: u32) -> Option<Self> {
+        Self::try_from(Integer::from_bits(bits)).ok()
+    }
+
+    /// Returns the stored bytes, which are in the byte order of `self`.
+    #[inline]
+    #[must_use]
+    pub const fn to_bytes(self) -> [u8; 4] {
+        self.0.to_bytes()
+    }
+
+    /// Creates a new value from `bytes`, which are expected to be in the byte
+    /// order of `Self`.
+    ///
+    /// Returns `None` if `bytes` do not encode a valid `char`.
+    #[inline]
+    #[must_use]
+    pub fn from_bytes(bytes: [u8; 4]) -> Option<Self> {
+        Self::try_from(Integer::<u32, O>::from_bytes(bytes)).ok()
+    }
+}
+
+impl<O: Order> fmt::Debug for Char<O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple(O::NAME).field(&self.to_native()).finish()
+    }
+}
+
+impl<O: Order> From<char> for Char<O> {
+    #[inline]
+    fn from(value: char) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<O: Order> From<Char<O>> for char {
+    #[inline]
+    fn from(value: Char<O>) -> Self {
+        value.to_native()
+    }
+}
+
+impl<O: Order> From<Char<O>> for Integer<u32, O> {
+    #[inline]
+    fn from(value: Char<O>) -> Self {
+        value.0
+    }
+}
+
+impl<O: Order> TryFrom<Integer<u32, O>> for Char<O> {
+    type Error = CharTryFromError;
+
+    /// Fails if the native value is not a valid `char`.
+    #[inline]
+    fn try_from(value: Integer<u32, O>) -> Result<Self, Self::Error> {
+        char::try_from(value.to_native())?;
+        Ok(Self(value))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::*;
+    use crate::BigEndian;
+    use std::format;
+
+    #[test]
+    fn round_trip() {
+        let be = BigEndianChar::new('🦀');
+        let le = LittleEndianChar::new('🦀');
+        assert_eq!(be.to_native(), '🦀');
+        assert_eq!(le.to_native(), '🦀');
+        assert_eq!(char::from(be), '🦀');
+
+        assert_eq!(be.to_bytes(), 0x1f980u32.to_be_bytes());
+        assert_eq!(le.to_bytes(), 0x1f980u32.to_le_bytes());
+        assert_eq!(BigEndianChar::from_bytes(be.to_bytes()), Some(be));
+        assert_eq!(BigEndianChar::from_bits(be.to_bits()), Some(be));
+        assert_eq!(format!("{be:?}"), "BigEndian('🦀')");
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(BigEndianChar::from_bytes(0xd800u32.to_be_bytes()), None);
+        assert_eq!(
+            LittleEndianChar::from_bytes(0x11_0000u32.to_le_bytes()),
+            None
+        );
+        assert!(BigEndianChar::try_from(BigEndian::new(0xd800u32)).is_err());
+        assert_eq!(
+            BigEndianChar::try_from(BigEndian::new(0x61u32)),
+            Ok(BigEndianChar::new('a'))
+        );
+    }
+}
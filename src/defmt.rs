@@ -0,0 +1,27 @@
+//! Formats the endian types with their native value, same as `Debug`.
+
+use ::defmt::{Format, Formatter};
+
+use crate::{Endian, Integer, Order};
+
+impl<T: Endian<T> + Format, O: Order> Format for Integer<T, O> {
+    fn format(&self, f: Formatter<'_>) {
+        ::defmt::write!(f, "{=str}({})", O::NAME, self.to_native());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ::defmt::Format;
+
+    use crate::{BigEndian, LittleEndian, NativeEndian};
+
+    fn assert_format<T: Format>() {}
+
+    #[test]
+    fn implemented() {
+        assert_format::<BigEndian<u32>>();
+        assert_format::<LittleEndian<i64>>();
+        assert_format::<NativeEndian<f32>>();
+    }
+}
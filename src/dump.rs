@@ -0,0 +1,74 @@
+//! Writing the stored bytes of values, e.g. of a parsed header, without
+//! allocating.
+
+use core::fmt;
+
+/// Types whose stored bytes can be written as hex.
+///
+/// This is implemented by the endian types of the primitive integers and
+/// floats, which write their bytes in the order in which they are stored, same
+/// as their `write_hex_bytes` method. Structs that consist of endian fields can
+/// implement it by dumping their fields in turn:
+///
+/// ```
+/// use core::fmt;
+/// use byte_endian::{BigEndian, ByteDump, LittleEndian};
+///
+/// struct Header {
+///     magic: BigEndian<u16>,
+///     length: LittleEndian<u16>,
+/// }
+///
+/// impl ByteDump for Header {
+///     fn dump_bytes(&self, w: &mut impl fmt::Write) -> fmt::Result {
+///         self.magic.dump_bytes(w)?;
+///         w.write_str(" ")?;
+///         self.length.dump_bytes(w)
+///     }
+/// }
+///
+/// let header = Header {
+///     magic: BigEndian::new(0xcafe),
+///     length: LittleEndian::new(0x10),
+/// };
+/// let mut out = String::new();
+/// header.dump_bytes(&mut out).unwrap();
+/// assert_eq!(out, "ca fe 10 00");
+/// ```
+pub trait ByteDump {
+    /// Writes the stored bytes of `self` to `w` as space separated hex.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `w` fails.
+    fn dump_bytes(&self, w: &mut impl fmt::Write) -> fmt::Result;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::Buf;
+    use crate::{BigEndian, LittleEndian};
+
+    #[test]
+    fn dump_bytes() {
+        let mut out = Buf::<8>::new();
+        BigEndian::new(0x1234u16).dump_bytes(&mut out).unwrap();
+        assert_eq!(out.as_str(), "12 34");
+
+        let mut out = Buf::<8>::new();
+        LittleEndian::new(0x1234u16).dump_bytes(&mut out).unwrap();
+        assert_eq!(out.as_str(), "34 12");
+
+        let mut out = Buf::<2>::new();
+        BigEndian::new(0u8).dump_bytes(&mut out).unwrap();
+        assert_eq!(out.as_str(), "00");
+
+        let mut out = Buf::<4>::new();
+        assert_eq!(
+            BigEndian::new(0x1234u16).dump_bytes(&mut out),
+            Err(fmt::Error)
+        );
+        assert_eq!(out.as_str(), "12 ");
+    }
+}
@@ -0,0 +1,22 @@
+use core::fmt;
+
+/// The error type returned when converting a byte slice to an endian type
+/// fails.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TryFromSliceError {
+    /// The slice has fewer bytes than the value.
+    TooShort,
+    /// The slice has more bytes than the value.
+    TooLong,
+}
+
+impl fmt::Display for TryFromSliceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooShort => f.write_str("byte slice is too short for the value"),
+            Self::TooLong => f.write_str("byte slice is too long for the value"),
+        }
+    }
+}
+
+impl core::error::Error for TryFromSliceError {}
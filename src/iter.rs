@@ -0,0 +1,64 @@
+use core::marker::PhantomData;
+use core::mem::size_of;
+
+/// An iterator that decodes consecutive values of type `W` from a byte slice.
+///
+/// Created by the `iter_from` method on the endian types. Once fewer bytes than
+/// the size of `W` are left, the iteration ends and the leftover bytes can be
+/// retrieved with [`Iter::remainder`].
+#[derive(Debug, Clone)]
+pub struct Iter<'a, W> {
+    pub(crate) bytes: &'a [u8],
+    _marker: PhantomData<W>,
+}
+
+impl<'a, W> Iter<'a, W> {
+    #[inline]
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the trailing bytes that are too few to decode another value.
+    #[inline]
+    #[must_use]
+    pub fn remainder(&self) -> &'a [u8] {
+        &self.bytes[self.bytes.len() - self.bytes.len() % size_of::<W>()..]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use crate::{BigEndian, LittleEndian};
+    use std::vec::Vec;
+
+    #[test]
+    fn iter_from() {
+        let bytes = [0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 3, 0xff];
+
+        let values: Vec<u32> = BigEndian::<u32>::iter_from(&bytes[..12])
+            .map(BigEndian::to_native)
+            .collect();
+        assert_eq!(values, [1, 2, 3]);
+
+        let mut iter = BigEndian::<u32>::iter_from(&bytes);
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.remainder(), [0xff]);
+        assert_eq!(iter.next(), Some(BigEndian::new(1)));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.by_ref().count(), 2);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.remainder(), [0xff]);
+
+        let mut iter = LittleEndian::<u16>::iter_from(&bytes[..3]);
+        assert_eq!(iter.next(), Some(LittleEndian::new(0)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.remainder(), [0]);
+
+        assert_eq!(LittleEndian::<u64>::iter_from(&[]).next(), None);
+    }
+}
@@ -1,11 +1,85 @@
 #![no_std]
 #![warn(clippy::pedantic)]
 
+use core::cmp::{Ordering, Reverse};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::{FusedIterator, Map, Product, Sum};
+use core::marker::PhantomData;
+use core::mem::{align_of, size_of};
+use core::net::{Ipv4Addr, Ipv6Addr};
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, ParseIntError, Saturating,
+    TryFromIntError, Wrapping,
+};
+use core::str::FromStr;
+use core::time::Duration;
+use core::{ops, ptr, slice};
+
+mod char;
+mod dump;
+mod error;
+mod iter;
+mod macros;
+mod ordered;
+pub mod prelude;
+mod tuple;
+
+pub use char::{BigEndianChar, Char, LittleEndianChar, NativeEndianChar};
+pub use dump::ByteDump;
+pub use error::TryFromSliceError;
+pub use iter::Iter;
+pub use ordered::OrderedNative;
+pub use tuple::{read_tuple, ReadFromPrefix};
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "defmt")]
+mod defmt;
+#[cfg(feature = "num-traits")]
+mod num_traits;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "alloc")]
+mod vec;
+#[cfg(all(test, feature = "zerocopy"))]
+mod zerocopy;
+
+#[cfg(feature = "serde")]
+pub use serde::RawBytes;
+
+// The atomic types need atomic read-modify-write operations, which some
+// embedded targets (e.g. `thumbv6m-none-eabi`) do not have at all.
+#[cfg(any(
+    target_has_atomic = "8",
+    target_has_atomic = "16",
+    target_has_atomic = "32",
+    target_has_atomic = "64",
+    target_has_atomic = "ptr"
+))]
+mod atomic;
+#[cfg(any(
+    target_has_atomic = "8",
+    target_has_atomic = "16",
+    target_has_atomic = "32",
+    target_has_atomic = "64",
+    target_has_atomic = "ptr"
+))]
+pub use atomic::{AtomicBigEndian, AtomicInteger, AtomicLittleEndian};
+
 macro_rules! impl_traits {
     // Implements `Endian` for the given endian types and implements the
-    // necessary traits for the big and little endian types.
+    // necessary traits for the endian wrappers of them.
     ($($endian_type:ident),+) => {
         $(
+            impl sealed::Sealed for $endian_type {}
+
             impl Endian<$endian_type> for $endian_type {
                 fn to_be(&self) -> $endian_type {
                     <$endian_type>::to_be(*self)
@@ -25,154 +99,3229 @@ macro_rules! impl_traits {
             }
         )+
 
-        impl_traits!(@make_impl $($endian_type),+ => LittleEndian);
-        impl_traits!(@make_impl $($endian_type),+ => BigEndian);
+        impl_traits!(@from_wrapper $($endian_type),+);
+        impl_traits!(@layout $($endian_type),+);
+        impl_traits!(@type_name $($endian_type),+);
+
+        impl_traits!(@const_impl $($endian_type),+ => LittleEndian, to_le, from_le);
+        impl_traits!(@const_impl $($endian_type),+ => BigEndian, to_be, from_be);
+        impl_traits!(@const_impl $($endian_type),+ => NativeEndian);
+
+        impl_traits!(@bytes_impl int $($endian_type),+);
+        impl_traits!(@int_impl $($endian_type),+);
     };
 
-    // Implements `From<T> for $type<T>` and `From<$type<T>> for T` where T
-    // is a subtype of Endian<T> and $type is either big or little endian.
-    (@make_impl $($endian_type:ident),+ => $type:ident) => {
-        impl<T: Endian<T>> From<T> for $type<T> {
-            #[inline]
-            fn from(value: T) -> Self {
-                Self::new(value)
+    // Implements the integer arithmetic methods for the given integer types.
+    // All of them are computed on the native values.
+    (@int_impl $($endian_type:ident),+) => {
+        $(
+            impl<O: Order> Integer<$endian_type, O> {
+                impl_traits!(@checked $endian_type => checked_add, checked_sub, checked_mul, checked_div);
+                impl_traits!(@binary $endian_type => wrapping_add, wrapping_sub, wrapping_mul);
+                impl_traits!(@binary $endian_type => saturating_add, saturating_sub, saturating_mul);
+                impl_traits!(@overflowing $endian_type => overflowing_add, overflowing_sub, overflowing_mul);
+                impl_traits!(@rotate $endian_type => rotate_left, rotate_right);
+                impl_traits!(@count $endian_type => count_ones, count_zeros, leading_zeros, trailing_zeros);
+
+                /// Reverses the order of the bits of the native value.
+                ///
+                /// Unlike [`swap_bytes`](Self::swap_bytes), this operates on the
+                /// native value and keeps it in the byte order of `self`.
+                #[inline]
+                #[must_use]
+                pub fn reverse_bits(self) -> Self {
+                    Self::new(self.to_native().reverse_bits())
+                }
+
+                #[doc = concat!("Same as [`", stringify!($endian_type), "::pow`].")]
+                #[inline]
+                #[must_use]
+                pub fn pow(self, exp: u32) -> Self {
+                    Self::new(self.to_native().pow(exp))
+                }
+
+                #[doc = concat!("Same as [`", stringify!($endian_type), "::from_str_radix`], but stores the")]
+                /// parsed value in the byte order of `Self`.
+                ///
+                /// # Errors
+                ///
+                /// Returns an error if `src` is not a valid number in the given
+                /// radix.
+                #[inline]
+                pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+                    <$endian_type>::from_str_radix(src, radix).map(Self::new)
+                }
             }
-        }
 
+            impl<O: Order> From<Wrapping<$endian_type>> for Integer<$endian_type, O> {
+                #[inline]
+                fn from(value: Wrapping<$endian_type>) -> Self {
+                    Self::new(value.0)
+                }
+            }
+
+            impl<O: Order> From<Integer<$endian_type, O>> for Wrapping<$endian_type> {
+                #[inline]
+                fn from(value: Integer<$endian_type, O>) -> Self {
+                    Wrapping(value.to_native())
+                }
+            }
+
+            impl<O: Order> From<Saturating<$endian_type>> for Integer<$endian_type, O> {
+                #[inline]
+                fn from(value: Saturating<$endian_type>) -> Self {
+                    Self::new(value.0)
+                }
+            }
+
+            impl<O: Order> From<Integer<$endian_type, O>> for Saturating<$endian_type> {
+                #[inline]
+                fn from(value: Integer<$endian_type, O>) -> Self {
+                    Saturating(value.to_native())
+                }
+            }
+
+            /// Parses a decimal number and stores it in the byte order of `Self`.
+            impl<O: Order> FromStr for Integer<$endian_type, O> {
+                type Err = ParseIntError;
+
+                #[inline]
+                fn from_str(src: &str) -> Result<Self, Self::Err> {
+                    src.parse().map(Self::new)
+                }
+            }
+        )+
+    };
+
+    (@binary $endian_type:ident => $($method:ident),+) => {
         $(
-            impl From<$type<$endian_type>> for $endian_type {
+            #[doc = concat!("Same as [`", stringify!($endian_type), "::", stringify!($method), "`].")]
+            #[inline]
+            #[must_use]
+            pub fn $method(self, rhs: Self) -> Self {
+                Self::new(self.to_native().$method(rhs.to_native()))
+            }
+        )+
+    };
+
+    // Implements lossless `From` conversions from the wrappers of narrower
+    // integer types, in the same byte order.
+    (@from $($from_type:ident => $($endian_type:ident),+);+) => {
+        $($(
+            impl<O: Order> From<Integer<$from_type, O>> for Integer<$endian_type, O> {
                 #[inline]
-                fn from(value: $type<$endian_type>) -> Self {
-                    value.to_native()
+                fn from(value: Integer<$from_type, O>) -> Self {
+                    Self::new(<$endian_type>::from(value.to_native()))
                 }
             }
-        )*
+        )+)+
     };
-}
 
-pub trait Endian<T>
-where
-    Self: Into<T> + Copy + Clone + Send + Sync,
-{
-    /// Converts `self` to big endian from the target’s endianness.
-    fn to_be(&self) -> T;
+    // Implements `TryFrom` from the wrappers of wider integer types, in the
+    // same byte order.
+    (@try_from $($from_type:ident => $($endian_type:ident),+);+) => {
+        $($(
+            impl<O: Order> TryFrom<Integer<$from_type, O>> for Integer<$endian_type, O> {
+                type Error = TryFromIntError;
 
-    /// Converts `self` to little endian from the target’s endianness.
-    fn to_le(&self) -> T;
+                /// Fails if the native value does not fit in the narrower type.
+                #[inline]
+                fn try_from(value: Integer<$from_type, O>) -> Result<Self, Self::Error> {
+                    <$endian_type>::try_from(value.to_native()).map(Self::new)
+                }
+            }
+        )+)+
+    };
 
-    /// Converts `value` from big endian to the target’s endianness.
-    fn from_be(value: T) -> T;
+    // Implements reinterpreting the bits of the given signed integer types as the
+    // unsigned ones of the same size, and the other way around. The stored bytes
+    // stay the same, so no conversions are needed.
+    (@cast $($signed_type:ident <=> $unsigned_type:ident),+) => {
+        $(
+            impl<O: Order> Integer<$signed_type, O> {
+                #[doc = concat!("Reinterprets the bits of the native value as an `", stringify!($unsigned_type), "`,")]
+                /// keeping the stored bytes as they are.
+                #[inline]
+                #[must_use]
+                pub const fn cast_unsigned(self) -> Integer<$unsigned_type, O> {
+                    Integer(<$unsigned_type>::from_ne_bytes(self.0.to_ne_bytes()), PhantomData)
+                }
+            }
 
-    /// Converts `value` from little endian to the target’s endianness.
-    fn from_le(value: T) -> T;
-}
+            impl<O: Order> Integer<$unsigned_type, O> {
+                #[doc = concat!("Reinterprets the bits of the native value as an `", stringify!($signed_type), "`,")]
+                /// keeping the stored bytes as they are.
+                #[inline]
+                #[must_use]
+                pub const fn cast_signed(self) -> Integer<$signed_type, O> {
+                    Integer(<$signed_type>::from_ne_bytes(self.0.to_ne_bytes()), PhantomData)
+                }
+            }
+        )+
+    };
 
-#[derive(Default, Debug, Copy, Clone, Eq, Hash, PartialEq)]
-#[repr(transparent)]
-pub struct BigEndian<T: Endian<T>>(T);
+    // Implements the methods that only exist for unsigned integers.
+    (@unsigned_impl $($endian_type:ident),+) => {
+        $(
+            impl<O: Order> Integer<$endian_type, O> {
+                /// Returns `true` if the native value is a power of two.
+                #[inline]
+                #[must_use]
+                pub fn is_power_of_two(self) -> bool {
+                    self.to_native().is_power_of_two()
+                }
 
-impl<T: Endian<T>> BigEndian<T> {
-    #[inline]
-    pub fn new(value: T) -> Self {
-        Self(value.to_be())
-    }
+                #[doc = concat!("Same as [`", stringify!($endian_type), "::next_power_of_two`].")]
+                #[inline]
+                #[must_use]
+                pub fn next_power_of_two(self) -> Self {
+                    Self::new(self.to_native().next_power_of_two())
+                }
 
-    /// Converts `self` from big endian to the target’s endianness.
-    #[inline]
-    pub fn to_native(self) -> T {
-        T::from_be(self.0)
-    }
+                #[doc = concat!("Same as [`", stringify!($endian_type), "::checked_next_power_of_two`].")]
+                #[inline]
+                #[must_use]
+                pub fn checked_next_power_of_two(self) -> Option<Self> {
+                    self.to_native().checked_next_power_of_two().map(Self::new)
+                }
 
-    #[inline]
-    pub fn to_bits(self) -> T {
-        self.0
-    }
-}
+                /// Returns the bits of the native value in `range`, where bit 0
+                /// is the least significant one, shifted down to the least
+                /// significant bits. An empty range gives 0.
+                ///
+                /// # Panics
+                ///
+                #[doc = concat!("Panics if `range.start > range.end` or `range.end > ", stringify!($endian_type), "::BITS`.")]
+                #[inline]
+                #[must_use]
+                pub fn bits(self, range: ops::Range<u32>) -> $endian_type {
+                    assert!(
+                        range.start <= range.end && range.end <= <$endian_type>::BITS,
+                        "bit range out of bounds"
+                    );
+                    let len = range.end - range.start;
+                    if len == 0 {
+                        return 0;
+                    }
+                    (self.to_native() >> range.start) & (<$endian_type>::MAX >> (<$endian_type>::BITS - len))
+                }
+            }
+        )+
+    };
 
-#[derive(Default, Debug, Copy, Clone, Eq, Hash, PartialEq)]
-#[repr(transparent)]
-pub struct LittleEndian<T: Endian<T>>(T);
+    // Implements the methods that only exist for signed integers.
+    (@signed_impl $($endian_type:ident),+) => {
+        $(
+            impl<O: Order> Integer<$endian_type, O> {
+                #[doc = concat!("Same as [`", stringify!($endian_type), "::abs`].")]
+                #[inline]
+                #[must_use]
+                pub fn abs(self) -> Self {
+                    Self::new(self.to_native().abs())
+                }
 
-impl<T: Endian<T>> LittleEndian<T> {
-    #[inline]
-    pub fn new(value: T) -> Self {
-        Self(value.to_le())
-    }
+                /// Returns the sign of the native value: `-1` if it is negative, `0`
+                /// if it is zero and `1` if it is positive.
+                #[inline]
+                #[must_use]
+                pub fn signum(self) -> $endian_type {
+                    self.to_native().signum()
+                }
 
-    /// Converts `self` from little endian to the target’s endianness.
-    #[inline]
-    pub fn to_native(self) -> T {
-        T::from_le(self.0)
-    }
+                /// Returns `true` if the native value is negative.
+                #[inline]
+                #[must_use]
+                pub fn is_negative(self) -> bool {
+                    self.to_native().is_negative()
+                }
 
-    #[inline]
-    pub fn to_bits(self) -> T {
-        self.0
-    }
-}
+                /// Returns `true` if the native value is positive.
+                #[inline]
+                #[must_use]
+                pub fn is_positive(self) -> bool {
+                    self.to_native().is_positive()
+                }
+            }
 
-impl_traits!(u8, u16, u32, u64, u128, usize);
+            impl<O: Order> ops::Neg for Integer<$endian_type, O> {
+                type Output = Self;
 
-#[cfg(test)]
-mod test {
-    use super::*;
+                #[inline]
+                fn neg(self) -> Self {
+                    Self::new(-self.to_native())
+                }
+            }
+        )+
+    };
 
-    #[test]
-    fn inside_packed() {
-        // This tests that no references are created by calling any of the methods on
-        // the endian types. Since, creating references to a packed field (which is not
-        // aligned) is undefined behaviour (`E0793`).
-        #[repr(C, packed)]
-        struct Packet(BigEndian<u64>, LittleEndian<u64>);
+    (@rotate $endian_type:ident => $($method:ident),+) => {
+        $(
+            #[doc = concat!("Same as [`", stringify!($endian_type), "::", stringify!($method), "`].")]
+            #[inline]
+            #[must_use]
+            pub fn $method(self, n: u32) -> Self {
+                Self::new(self.to_native().$method(n))
+            }
+        )+
+    };
 
-        let packet = Packet(0xfe.into(), 0xfe.into());
+    (@count $endian_type:ident => $($method:ident),+) => {
+        $(
+            #[doc = concat!("Same as [`", stringify!($endian_type), "::", stringify!($method), "`].")]
+            #[inline]
+            #[must_use]
+            pub fn $method(self) -> u32 {
+                self.to_native().$method()
+            }
+        )+
+    };
 
-        assert_eq!(packet.0.to_native(), 0xfe);
-        if cfg!(byte_endian = "big_endian") {
-            assert_eq!(packet.0.to_bits(), 0xfeu64);
-        } else {
-            assert_eq!(packet.0.to_bits(), 0xfe00000000000000u64);
-        }
+    (@overflowing $endian_type:ident => $($method:ident),+) => {
+        $(
+            #[doc = concat!("Same as [`", stringify!($endian_type), "::", stringify!($method), "`].")]
+            ///
+            /// Returns the wrapped result along with whether an overflow occurred.
+            #[inline]
+            #[must_use]
+            pub fn $method(self, rhs: Self) -> (Self, bool) {
+                let (value, overflowed) = self.to_native().$method(rhs.to_native());
+                (Self::new(value), overflowed)
+            }
+        )+
+    };
 
-        assert_eq!(packet.1.to_native(), 0xfe);
-        if cfg!(byte_endian = "big_endian") {
-            assert_eq!(packet.1.to_bits(), 0xfe00000000000000u64);
-        } else {
-            assert_eq!(packet.1.to_bits(), 0xfeu64);
+    (@checked $endian_type:ident => $($method:ident),+) => {
+        $(
+            #[doc = concat!("Same as [`", stringify!($endian_type), "::", stringify!($method), "`].")]
+            ///
+            /// Returns `None` if the operation overflows (or divides by zero).
+            #[inline]
+            #[must_use]
+            pub fn $method(self, rhs: Self) -> Option<Self> {
+                self.to_native().$method(rhs.to_native()).map(Self::new)
+            }
+        )+
+    };
+
+    // Converts a primitive to and from its bytes in a `const` context. The
+    // `to_ne_bytes` and `from_ne_bytes` methods of the floats are only `const`
+    // since Rust 1.83, so they are reinterpreted through a union instead.
+    (@to_ne_bytes int $endian_type:ident, $value:expr) => {
+        $value.to_ne_bytes()
+    };
+    (@to_ne_bytes float $endian_type:ident, $value:expr) => {{
+        union Bytes {
+            value: $endian_type,
+            bytes: [u8; size_of::<$endian_type>()],
         }
-    }
+        // SAFETY: The array has the same size as the float, and every bit
+        // pattern is a valid array of bytes.
+        unsafe { Bytes { value: $value }.bytes }
+    }};
+    (@from_ne_bytes int $endian_type:ident, $bytes:expr) => {
+        <$endian_type>::from_ne_bytes($bytes)
+    };
+    (@from_ne_bytes float $endian_type:ident, $bytes:expr) => {{
+        union Bytes {
+            value: $endian_type,
+            bytes: [u8; size_of::<$endian_type>()],
+        }
+        // SAFETY: The float has the same size as the array, and every bit
+        // pattern is a valid float.
+        unsafe { Bytes { bytes: $bytes }.value }
+    }};
 
-    #[test]
-    fn new_to_native() {
-        let be_value = BigEndian::new(12345u64);
-        assert_eq!(be_value, 12345u64.into());
-        assert_eq!(be_value.to_native(), 12345u64);
+    // Implements conversions between `Integer<T, O>` and its stored bytes for
+    // the given types. The size of the byte array depends on the type, so
+    // these cannot be implemented generically.
+    (@bytes_impl $kind:ident $($endian_type:ident),+) => {
+        $(
+            impl<O: Order> Integer<$endian_type, O> {
+                /// Returns the stored bytes, which are in the byte order of `self`
+                /// regardless of the target’s endianness.
+                #[inline]
+                #[must_use]
+                pub const fn to_bytes(self) -> [u8; size_of::<$endian_type>()] {
+                    impl_traits!(@to_ne_bytes $kind $endian_type, self.0)
+                }
 
-        let be_native: u64 = be_value.into();
-        assert_eq!(be_native, 12345u64);
+                /// Creates a new value from the little endian bytes of the native
+                /// value, regardless of the byte order of `Self`.
+                #[inline]
+                #[must_use]
+                pub fn from_le_bytes(bytes: [u8; size_of::<$endian_type>()]) -> Self {
+                    Self::new(<$endian_type>::from_le_bytes(bytes))
+                }
 
-        let le_value = LittleEndian::new(12345u64);
-        assert_eq!(le_value, 12345u64.into());
-        assert_eq!(le_value.to_native(), 12345u64);
+                /// Creates a new value from the big endian bytes of the native
+                /// value, regardless of the byte order of `Self`.
+                #[inline]
+                #[must_use]
+                pub fn from_be_bytes(bytes: [u8; size_of::<$endian_type>()]) -> Self {
+                    Self::new(<$endian_type>::from_be_bytes(bytes))
+                }
 
-        let native: u64 = le_value.into();
-        assert_eq!(native, 12345u64);
-    }
+                /// Returns the big endian bytes of the native value, regardless of
+                /// the byte order of `self` and of the target. Same as
+                /// `to_be_bytes`.
+                ///
+                /// Unlike the `Hash` implementation, feeding these to a hasher
+                /// gives the same hash on all targets.
+                #[inline]
+                #[must_use]
+                pub fn stable_bytes(self) -> [u8; size_of::<$endian_type>()] {
+                    self.to_be_bytes()
+                }
 
-    #[test]
-    fn new_to_bits() {
-        let value = BigEndian::new(0xfeu64);
-        if cfg!(byte_endian = "big_endian") {
-            assert_eq!(value.to_bits(), 0xfeu64);
-        } else {
-            assert_eq!(value.to_bits(), 0xfe00000000000000u64);
-        }
+                /// Returns the `i`-th stored byte, in the byte order of `self`.
+                ///
+                /// Returns `None` if `i` is out of range.
+                #[inline]
+                #[must_use]
+                pub fn nth_byte(self, i: usize) -> Option<u8> {
+                    self.to_bytes().get(i).copied()
+                }
 
-        let value = LittleEndian::new(0xfeu64);
-        if cfg!(byte_endian = "big_endian") {
-            assert_eq!(value.to_bits(), 0xfe00000000000000u64);
-        } else {
-            assert_eq!(value.to_bits(), 0xfeu64);
-        }
+                /// Returns whether the stored bytes are equal to `bytes`, without
+                /// converting them, e.g. for matching magic numbers.
+                ///
+                /// Returns `false` if the length of `bytes` is not the size of
+                /// `self`.
+                #[inline]
+                #[must_use]
+                pub fn bytes_eq(self, bytes: &[u8]) -> bool {
+                    self.to_bytes() == bytes
+                }
+
+                /// Returns the bytes of the native value in little endian,
+                /// regardless of the byte order of `self`.
+                #[inline]
+                #[must_use]
+                pub fn to_le_bytes(self) -> [u8; size_of::<$endian_type>()] {
+                    self.to_native().to_le_bytes()
+                }
+
+                /// Returns the bytes of the native value in big endian, regardless
+                /// of the byte order of `self`.
+                #[inline]
+                #[must_use]
+                pub fn to_be_bytes(self) -> [u8; size_of::<$endian_type>()] {
+                    self.to_native().to_be_bytes()
+                }
+
+                /// Creates a new value from `bytes`, which are expected to be in
+                /// the byte order of `Self`.
+                #[inline]
+                #[must_use]
+                pub const fn from_bytes(bytes: [u8; size_of::<$endian_type>()]) -> Self {
+                    Self(impl_traits!(@from_ne_bytes $kind $endian_type, bytes), PhantomData)
+                }
+
+                /// Reads a value from the first bytes of `bytes`, which are expected
+                /// to be in the byte order of `Self`.
+                ///
+                /// Returns `None` if `bytes` is too short.
+                #[inline]
+                #[must_use]
+                pub fn read_from(bytes: &[u8]) -> Option<Self> {
+                    Self::read_from_prefix(bytes).map(|(value, _)| value)
+                }
+
+                /// Same as `read_from`, but also returns the remaining bytes.
+                #[inline]
+                #[must_use]
+                pub fn read_from_prefix(bytes: &[u8]) -> Option<(Self, &[u8])> {
+                    let (bytes, rest) = bytes.split_first_chunk()?;
+                    Some((Self::from_bytes(*bytes), rest))
+                }
+
+                /// Returns an iterator that decodes consecutive values from `bytes`,
+                /// which are expected to be in the byte order of `Self`.
+                #[inline]
+                #[must_use]
+                pub fn iter_from(bytes: &[u8]) -> Iter<'_, Self> {
+                    Iter::new(bytes)
+                }
+
+                /// Writes the stored bytes to the front of `out`, leaving the rest
+                /// of it untouched.
+                ///
+                /// Returns `None` if `out` is too short.
+                #[inline]
+                pub fn write_to(self, out: &mut [u8]) -> Option<()> {
+                    let (out, _) = out.split_first_chunk_mut()?;
+                    *out = self.to_bytes();
+                    Some(())
+                }
+
+                /// Writes the stored bytes to `w` as space separated hex, in the
+                /// order in which they are stored (e.g. `12 34 56 78`).
+                ///
+                /// # Errors
+                ///
+                /// Returns an error if writing to `w` fails.
+                #[inline]
+                pub fn write_hex_bytes(self, w: &mut impl fmt::Write) -> fmt::Result {
+                    self.write_hex_bytes_with(w, " ")
+                }
+
+                /// Reinterprets the longest prefix of `bytes` that is a whole
+                /// number of values as a slice of `Self`, without copying, and
+                /// returns it along with the remaining bytes.
+                ///
+                /// If `bytes` is not aligned for `Self`, an empty slice and all of
+                /// `bytes` are returned.
+                #[must_use]
+                // The alignment of the cast pointer is checked before it is used.
+                #[allow(clippy::cast_ptr_alignment)]
+                pub fn as_endian_slice(bytes: &[u8]) -> (&[Self], &[u8]) {
+                    // `align_to` is allowed to return everything in its prefix even
+                    // if `bytes` is aligned, so the alignment is checked directly.
+                    let ptr = bytes.as_ptr().cast::<Self>();
+                    if !ptr.is_aligned() {
+                        return (&[], bytes);
+                    }
+
+                    let len = bytes.len() / size_of::<Self>();
+                    // SAFETY: `ptr` is aligned and valid for reads of `len` values,
+                    // which are within `bytes`, and every bit pattern is a valid
+                    // value of the primitive that `Self` is `#[repr(transparent)]`
+                    // over.
+                    let values = unsafe { slice::from_raw_parts(ptr, len) };
+                    (values, &bytes[len * size_of::<Self>()..])
+                }
+
+                /// Same as `write_hex_bytes`, but separates the bytes with
+                /// `separator`.
+                ///
+                /// # Errors
+                ///
+                /// Returns an error if writing to `w` fails.
+                pub fn write_hex_bytes_with(self, w: &mut impl fmt::Write, separator: &str) -> fmt::Result {
+                    for (i, byte) in self.to_bytes().into_iter().enumerate() {
+                        if i != 0 {
+                            w.write_str(separator)?;
+                        }
+                        write!(w, "{byte:02x}")?;
+                    }
+                    Ok(())
+                }
+            }
+
+            /// Converts a slice of exactly `size_of::<Self>()` bytes, which are
+            /// expected to be in the byte order of `Self`.
+            impl<O: Order> TryFrom<&[u8]> for Integer<$endian_type, O> {
+                type Error = TryFromSliceError;
+
+                #[inline]
+                fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+                    match bytes.try_into() {
+                        Ok(bytes) => Ok(Self::from_bytes(bytes)),
+                        Err(_) if bytes.len() < size_of::<Self>() => Err(TryFromSliceError::TooShort),
+                        Err(_) => Err(TryFromSliceError::TooLong),
+                    }
+                }
+            }
+
+            /// Converts the stored bytes, which are expected to be in the byte
+            /// order of `Self`. Same as `from_bytes`.
+            impl<O: Order> From<[u8; size_of::<$endian_type>()]> for Integer<$endian_type, O> {
+                #[inline]
+                fn from(bytes: [u8; size_of::<$endian_type>()]) -> Self {
+                    Self::from_bytes(bytes)
+                }
+            }
+
+            impl<O: Order> ByteDump for Integer<$endian_type, O> {
+                #[inline]
+                fn dump_bytes(&self, w: &mut impl fmt::Write) -> fmt::Result {
+                    self.write_hex_bytes(w)
+                }
+            }
+
+            impl<O: Order> ReadFromPrefix for Integer<$endian_type, O> {
+                #[inline]
+                fn read_from_prefix(bytes: &[u8]) -> Option<(Self, &[u8])> {
+                    Self::read_from_prefix(bytes)
+                }
+            }
+
+            impl<O: Order> Iterator for Iter<'_, Integer<$endian_type, O>> {
+                type Item = Integer<$endian_type, O>;
+
+                #[inline]
+                fn next(&mut self) -> Option<Self::Item> {
+                    let (value, rest) = Self::Item::read_from_prefix(self.bytes)?;
+                    self.bytes = rest;
+                    Some(value)
+                }
+
+                #[inline]
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    let len = self.bytes.len() / size_of::<Self::Item>();
+                    (len, Some(len))
+                }
+            }
+
+            impl<O: Order> ExactSizeIterator for Iter<'_, Integer<$endian_type, O>> {}
+            impl<O: Order> FusedIterator for Iter<'_, Integer<$endian_type, O>> {}
+
+            /// Returns the stored bytes, in the byte order of `self`.
+            impl<O: Order> AsRef<[u8]> for Integer<$endian_type, O> {
+                #[inline]
+                fn as_ref(&self) -> &[u8] {
+                    // SAFETY: `self` is `#[repr(transparent)]` over a primitive
+                    // without padding, so all of its bytes are initialized.
+                    unsafe {
+                        slice::from_raw_parts(ptr::from_ref(self).cast::<u8>(), size_of::<Self>())
+                    }
+                }
+            }
+
+            /// Returns the stored bytes, in the byte order of `self`.
+            impl<O: Order> AsMut<[u8]> for Integer<$endian_type, O> {
+                #[inline]
+                fn as_mut(&mut self) -> &mut [u8] {
+                    // SAFETY: Same as above, and every bit pattern is a valid value
+                    // of the primitive.
+                    unsafe {
+                        slice::from_raw_parts_mut(ptr::from_mut(self).cast::<u8>(), size_of::<Self>())
+                    }
+                }
+            }
+        )+
+    };
+
+    // Implements `const` conversions for the given integer types, as the
+    // generic `new` and `to_native` go through the `Endian` trait and cannot be
+    // `const`.
+    (@const_impl $($endian_type:ident),+ => $type:ident, $to:ident, $from:ident) => {
+        $(
+            impl $type<$endian_type> {
+                /// The value `0`.
+                pub const ZERO: Self = Self::new_const(0);
+
+                /// The value `1`.
+                pub const ONE: Self = Self::new_const(1);
+
+                #[doc = concat!("The smallest value, [`", stringify!($endian_type), "::MIN`].")]
+                pub const MIN: Self = Self::new_const(<$endian_type>::MIN);
+
+                #[doc = concat!("The largest value, [`", stringify!($endian_type), "::MAX`].")]
+                pub const MAX: Self = Self::new_const(<$endian_type>::MAX);
+
+                /// Same as `new`, but usable in `const` and `static` items.
+                #[inline]
+                #[must_use]
+                pub const fn new_const(value: $endian_type) -> Self {
+                    Self(<$endian_type>::$to(value), PhantomData)
+                }
+
+                /// Same as `to_native`, but usable in `const` contexts.
+                #[inline]
+                #[must_use]
+                pub const fn to_native_const(self) -> $endian_type {
+                    <$endian_type>::$from(self.0)
+                }
+            }
+        )+
+    };
+
+    (@const_impl $($endian_type:ident),+ => $type:ident) => {
+        $(
+            impl $type<$endian_type> {
+                /// The value `0`.
+                pub const ZERO: Self = Self::new_const(0);
+
+                /// The value `1`.
+                pub const ONE: Self = Self::new_const(1);
+
+                #[doc = concat!("The smallest value, [`", stringify!($endian_type), "::MIN`].")]
+                pub const MIN: Self = Self::new_const(<$endian_type>::MIN);
+
+                #[doc = concat!("The largest value, [`", stringify!($endian_type), "::MAX`].")]
+                pub const MAX: Self = Self::new_const(<$endian_type>::MAX);
+
+                /// Same as `new`, but usable in `const` and `static` items.
+                #[inline]
+                #[must_use]
+                pub const fn new_const(value: $endian_type) -> Self {
+                    Self(value, PhantomData)
+                }
+
+                /// Same as `to_native`, but usable in `const` contexts.
+                #[inline]
+                #[must_use]
+                pub const fn to_native_const(self) -> $endian_type {
+                    self.0
+                }
+            }
+        )+
+    };
+
+    // Implements `Endian` for the given floating point types. Floats do not
+    // have byte order intrinsics, so the bytes of their integer representation
+    // are swapped instead. Going through `to_bits` and `from_bits` preserves the
+    // exact bit pattern (including NaN payloads and the sign of zero).
+    (@float $($float_type:ident => $bits_type:ident),+) => {
+        $(
+            impl sealed::Sealed for $float_type {}
+
+            impl Endian<$float_type> for $float_type {
+                fn to_be(&self) -> $float_type {
+                    <$float_type>::from_bits(self.to_bits().to_be())
+                }
+
+                fn to_le(&self) -> $float_type {
+                    <$float_type>::from_bits(self.to_bits().to_le())
+                }
+
+                fn from_be(value: $float_type) -> $float_type {
+                    <$float_type>::from_bits(<$bits_type>::from_be(value.to_bits()))
+                }
+
+                fn from_le(value: $float_type) -> $float_type {
+                    <$float_type>::from_bits(<$bits_type>::from_le(value.to_bits()))
+                }
+            }
+        )+
+
+        impl_traits!(@from_wrapper $($float_type),+);
+        impl_traits!(@layout $($float_type),+);
+        impl_traits!(@type_name $($float_type),+);
+        impl_traits!(@bytes_impl float $($float_type),+);
+    };
+
+    // Implements `Endian` for the given non-zero integer types by swapping
+    // the bytes of the underlying integer.
+    (@nonzero $($nonzero_type:ident => $int_type:ident),+) => {
+        $(
+            impl sealed::Sealed for $nonzero_type {}
+
+            // SAFETY: Swapping the bytes of a non-zero integer cannot make it
+            // zero, so all of the `new_unchecked` calls below are sound.
+            impl Endian<$nonzero_type> for $nonzero_type {
+                fn to_be(&self) -> $nonzero_type {
+                    unsafe { <$nonzero_type>::new_unchecked(self.get().to_be()) }
+                }
+
+                fn to_le(&self) -> $nonzero_type {
+                    unsafe { <$nonzero_type>::new_unchecked(self.get().to_le()) }
+                }
+
+                fn from_be(value: $nonzero_type) -> $nonzero_type {
+                    unsafe { <$nonzero_type>::new_unchecked(<$int_type>::from_be(value.get())) }
+                }
+
+                fn from_le(value: $nonzero_type) -> $nonzero_type {
+                    unsafe { <$nonzero_type>::new_unchecked(<$int_type>::from_le(value.get())) }
+                }
+            }
+        )+
+
+        $(
+            impl<O: Order> Integer<$nonzero_type, O> {
+                /// Creates a new value from the raw stored `bits`, which are
+                /// expected to already be in the byte order of `Self`.
+                ///
+                /// Returns `None` if `bits` is zero, which is the case exactly if
+                /// the native value would be zero.
+                #[inline]
+                #[must_use]
+                pub const fn try_from_bits(bits: $int_type) -> Option<Self> {
+                    match <$nonzero_type>::new(bits) {
+                        Some(bits) => Some(Self::from_bits(bits)),
+                        None => None,
+                    }
+                }
+            }
+        )+
+
+        impl_traits!(@from_wrapper $($nonzero_type),+);
+        impl_traits!(@layout $($nonzero_type),+);
+        impl_traits!(@type_name $($nonzero_type),+);
+    };
+
+    // Implements the `TYPE_NAME` constants for the given types, in each of the
+    // byte orders.
+    (@type_name $($endian_type:ident),+) => {
+        $(
+            impl_traits!(@type_name $endian_type => BigEndian, "be");
+            impl_traits!(@type_name $endian_type => LittleEndian, "le");
+            impl_traits!(@type_name $endian_type => NativeEndian, "ne");
+        )+
+    };
+
+    (@type_name $endian_type:ident => $type:ident, $prefix:literal) => {
+        impl $type<$endian_type> {
+            #[doc = concat!("A short name of the type for diagnostics, `\"", $prefix, ":", stringify!($endian_type), "\"`.")]
+            pub const TYPE_NAME: &'static str = concat!($prefix, ":", stringify!($endian_type));
+        }
+    };
+
+    // Asserts at compile time that the wrappers of the given types have the
+    // same size and alignment as the types themselves, which code relying on
+    // the layout (e.g. of `#[repr(C)]` structs) depends on.
+    (@layout $($endian_type:ident),+) => {
+        $(
+            const _: () = {
+                assert!(size_of::<BigEndian<$endian_type>>() == size_of::<$endian_type>());
+                assert!(size_of::<LittleEndian<$endian_type>>() == size_of::<$endian_type>());
+                assert!(size_of::<NativeEndian<$endian_type>>() == size_of::<$endian_type>());
+                assert!(align_of::<BigEndian<$endian_type>>() == align_of::<$endian_type>());
+                assert!(align_of::<LittleEndian<$endian_type>>() == align_of::<$endian_type>());
+                assert!(align_of::<NativeEndian<$endian_type>>() == align_of::<$endian_type>());
+            };
+        )+
+    };
+
+    // Implements `From<Integer<T, O>> for T` and `PartialEq<Integer<T, O>> for T`
+    // for each of the given endian types. These cannot be implemented
+    // generically over `T` due to the orphan rules.
+    (@from_wrapper $($endian_type:ident),+) => {
+        $(
+            impl<O: Order> From<Integer<$endian_type, O>> for $endian_type {
+                #[inline]
+                fn from(value: Integer<$endian_type, O>) -> Self {
+                    value.to_native()
+                }
+            }
+
+            impl<O: Order> PartialEq<Integer<$endian_type, O>> for $endian_type {
+                #[inline]
+                fn eq(&self, other: &Integer<$endian_type, O>) -> bool {
+                    *self == other.to_native()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_fmt {
+    // Implements the given formatting traits for `Integer<T, O>` by forwarding
+    // to the native value, so that all of the formatter flags are honored.
+    ($($fmt:ident),+) => {
+        $(
+            impl<T: Endian<T> + fmt::$fmt, O: Order> fmt::$fmt for Integer<T, O> {
+                #[inline]
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::$fmt::fmt(&self.to_native(), f)
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_ops {
+    // Implements the given arithmetic operators (and their assigning variants)
+    // for `Integer<T, O>` by converting both operands to native, applying the
+    // operator and converting the result back.
+    (@native $($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident);+) => {
+        $(
+            /// The operands are converted to native and the result is converted
+            /// back, so this incurs a byte swap per operand and another one for
+            /// the result when the stored byte order is not the target’s.
+            impl<T: Endian<T> + ops::$trait<Output = T>, O: Order> ops::$trait for Integer<T, O> {
+                type Output = Self;
+
+                #[inline]
+                fn $method(self, rhs: Self) -> Self {
+                    Self::new(ops::$trait::$method(self.to_native(), rhs.to_native()))
+                }
+            }
+
+            impl<T: Endian<T> + ops::$trait<Output = T>, O: Order> ops::$assign_trait for Integer<T, O> {
+                #[inline]
+                fn $assign_method(&mut self, rhs: Self) {
+                    *self = ops::$trait::$method(*self, rhs);
+                }
+            }
+        )+
+    };
+
+    // Implements the given bitwise operators (and their assigning variants)
+    // for `Integer<T, O>`. These act on each bit independently, so they can be
+    // applied directly to the stored bits without any byte swapping.
+    (@bits $($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident);+) => {
+        $(
+            impl<T: Endian<T> + ops::$trait<Output = T>, O: Order> ops::$trait for Integer<T, O> {
+                type Output = Self;
+
+                #[inline]
+                fn $method(self, rhs: Self) -> Self {
+                    Self(ops::$trait::$method(self.0, rhs.0), PhantomData)
+                }
+            }
+
+            impl<T: Endian<T> + ops::$trait<Output = T>, O: Order> ops::$assign_trait for Integer<T, O> {
+                #[inline]
+                fn $assign_method(&mut self, rhs: Self) {
+                    *self = ops::$trait::$method(*self, rhs);
+                }
+            }
+        )+
+
+        impl<T: Endian<T> + ops::Not<Output = T>, O: Order> ops::Not for Integer<T, O> {
+            type Output = Self;
+
+            #[inline]
+            fn not(self) -> Self {
+                Self(!self.0, PhantomData)
+            }
+        }
+    };
+
+    // Implements the given shift operators (and their assigning variants) for
+    // `Integer<T, O>`. Unlike the bitwise operators, shifts move bits across
+    // byte boundaries and hence have to be applied to the native value.
+    (@shift $($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident);+) => {
+        $(
+            impl<T: Endian<T> + ops::$trait<u32, Output = T>, O: Order> ops::$trait<u32> for Integer<T, O> {
+                type Output = Self;
+
+                #[inline]
+                fn $method(self, rhs: u32) -> Self {
+                    Self::new(ops::$trait::$method(self.to_native(), rhs))
+                }
+            }
+
+            impl<T: Endian<T> + ops::$trait<u32, Output = T>, O: Order> ops::$assign_trait<u32> for Integer<T, O> {
+                #[inline]
+                fn $assign_method(&mut self, rhs: u32) {
+                    *self = ops::$trait::$method(*self, rhs);
+                }
+            }
+        )+
+    };
+}
+
+/// Whether the target is big endian.
+pub const HOST_IS_BIG_ENDIAN: bool = cfg!(target_endian = "big");
+
+/// Returns whether the target is big endian. Same as [`HOST_IS_BIG_ENDIAN`].
+#[inline]
+#[must_use]
+pub const fn host_is_big_endian() -> bool {
+    HOST_IS_BIG_ENDIAN
+}
+
+/// Converts each element of `slice` in place from the target’s endianness to
+/// big endian. This is a no-op on big endian targets.
+#[inline]
+pub fn swap_slice_to_be<T: Endian<T>>(slice: &mut [T]) {
+    swap_slice_to_order::<Be, T>(slice);
+}
+
+/// Converts each element of `slice` in place from the target’s endianness to
+/// little endian. This is a no-op on little endian targets.
+#[inline]
+pub fn swap_slice_to_le<T: Endian<T>>(slice: &mut [T]) {
+    swap_slice_to_order::<Le, T>(slice);
+}
+
+/// Converts each element of `slice` in place from the target’s endianness to
+/// the byte order `O`.
+#[inline]
+pub fn swap_slice_to_order<O: Order, T: Endian<T>>(slice: &mut [T]) {
+    for element in slice {
+        *element = O::to_order(*element);
+    }
+}
+
+/// Feeds the native value of `value` into `state`, same as its `Hash`
+/// implementation.
+///
+/// The hash matches the one of the bare native value, but like it, it may
+/// differ between targets. Use `stable_bytes` for hashes that are the same on
+/// all targets.
+#[inline]
+pub fn hash_native<H: Hasher, T: Endian<T> + Hash, O: Order>(value: &Integer<T, O>, state: &mut H) {
+    value.to_native().hash(state);
+}
+
+/// Creates a `Duration` from stored seconds and nanoseconds, e.g. of a
+/// timestamp field.
+///
+/// # Panics
+///
+/// Same as [`Duration::new`], if carrying the nanoseconds over into the
+/// seconds overflows.
+#[inline]
+#[must_use]
+pub fn duration_from<O: Order, P: Order>(
+    secs: Integer<u64, O>,
+    nanos: Integer<u32, P>,
+) -> Duration {
+    Duration::new(secs.to_native(), nanos.to_native())
+}
+
+/// Types that can be stored in a specific byte order by an [`Integer`].
+///
+/// This trait is sealed, so that the endian types can rely on the conversions
+/// being correct. It is implemented for the primitive integers and floats,
+/// their non-zero variants, `bool` and arrays of these.
+///
+/// ```compile_fail
+/// use byte_endian::Endian;
+///
+/// #[derive(Clone, Copy)]
+/// struct Foo(u32);
+///
+/// impl Endian<Foo> for Foo {
+///     fn to_be(&self) -> Foo {
+///         *self
+///     }
+///
+///     fn to_le(&self) -> Foo {
+///         *self
+///     }
+///
+///     fn from_be(value: Foo) -> Foo {
+///         value
+///     }
+///
+///     fn from_le(value: Foo) -> Foo {
+///         value
+///     }
+/// }
+/// ```
+pub trait Endian<T>: sealed::Sealed
+where
+    Self: Into<T> + Copy + Clone + Send + Sync,
+{
+    /// Converts `self` to big endian from the target’s endianness.
+    fn to_be(&self) -> T;
+
+    /// Converts `self` to little endian from the target’s endianness.
+    fn to_le(&self) -> T;
+
+    /// Converts `value` from big endian to the target’s endianness.
+    fn from_be(value: T) -> T;
+
+    /// Converts `value` from little endian to the target’s endianness.
+    fn from_le(value: T) -> T;
+}
+
+/// Extension methods for wrapping native values in the endian types.
+pub trait ToEndian: Endian<Self> + Sized {
+    /// Same as [`BigEndian::new`].
+    #[inline]
+    #[must_use]
+    fn to_be_wrapped(self) -> BigEndian<Self> {
+        BigEndian::new(self)
+    }
+
+    /// Same as [`LittleEndian::new`].
+    #[inline]
+    #[must_use]
+    fn to_le_wrapped(self) -> LittleEndian<Self> {
+        LittleEndian::new(self)
+    }
+}
+
+impl<T: Endian<T>> ToEndian for T {}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for super::Be {}
+    impl Sealed for super::Le {}
+    impl Sealed for super::Ne {}
+}
+
+/// A byte order in which an [`Integer`] stores its value.
+///
+/// This is implemented by the [`Be`], [`Le`] and [`Ne`] markers, and allows
+/// writing code that is generic over the byte order.
+pub trait Order:
+    sealed::Sealed + Copy + Default + fmt::Debug + Eq + Ord + Hash + Send + Sync + 'static
+{
+    /// The name of the type alias of `Integer<T, Self>`.
+    const NAME: &'static str;
+
+    /// Converts `value` from the target’s endianness to this byte order.
+    fn to_order<T: Endian<T>>(value: T) -> T;
+
+    /// Converts `value` from this byte order to the target’s endianness.
+    fn from_order<T: Endian<T>>(value: T) -> T;
+}
+
+/// Big endian byte order.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Be;
+
+/// Little endian byte order.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Le;
+
+/// The target’s byte order.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ne;
+
+impl Order for Be {
+    const NAME: &'static str = "BigEndian";
+
+    #[inline]
+    fn to_order<T: Endian<T>>(value: T) -> T {
+        value.to_be()
+    }
+
+    #[inline]
+    fn from_order<T: Endian<T>>(value: T) -> T {
+        T::from_be(value)
+    }
+}
+
+impl Order for Le {
+    const NAME: &'static str = "LittleEndian";
+
+    #[inline]
+    fn to_order<T: Endian<T>>(value: T) -> T {
+        value.to_le()
+    }
+
+    #[inline]
+    fn from_order<T: Endian<T>>(value: T) -> T {
+        T::from_le(value)
+    }
+}
+
+impl Order for Ne {
+    const NAME: &'static str = "NativeEndian";
+
+    #[inline]
+    fn to_order<T: Endian<T>>(value: T) -> T {
+        value
+    }
+
+    #[inline]
+    fn from_order<T: Endian<T>>(value: T) -> T {
+        value
+    }
+}
+
+/// A value of type `T` stored in the byte order `O`.
+///
+/// Usually this is used through one of the [`BigEndian`], [`LittleEndian`] and
+/// [`NativeEndian`] aliases, while the order parameter allows code to be
+/// generic over the byte order.
+///
+/// Values can also be created from their stored bytes, which must have exactly
+/// the size of `T`:
+///
+/// ```
+/// use byte_endian::BigEndian;
+///
+/// let value = BigEndian::<u32>::from([0x12, 0x34, 0x56, 0x78]);
+/// assert_eq!(value.to_native(), 0x1234_5678);
+/// ```
+///
+/// ```compile_fail
+/// use byte_endian::BigEndian;
+///
+/// let value = BigEndian::<u32>::from([0x12, 0x34, 0x56]);
+/// ```
+///
+/// ```compile_fail
+/// use byte_endian::LittleEndian;
+///
+/// let value = LittleEndian::<u16>::from([0x12, 0x34, 0x56, 0x78]);
+/// ```
+///
+/// Note that the size of `usize` and `isize` depends on the target’s pointer
+/// width, and so does the length of their stored bytes. Prefer the fixed width
+/// integer types for portable formats, or use [`assert_pointer_width!`] to
+/// reject targets with an unexpected width at compile time.
+#[derive(Default, Copy, Clone)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        ::zerocopy::FromBytes,
+        ::zerocopy::IntoBytes,
+        ::zerocopy::Immutable,
+        ::zerocopy::KnownLayout
+    )
+)]
+#[repr(transparent)]
+pub struct Integer<T: Endian<T>, O: Order>(T, PhantomData<O>);
+
+/// A value stored in big endian.
+pub type BigEndian<T> = Integer<T, Be>;
+
+/// A value stored in little endian.
+pub type LittleEndian<T> = Integer<T, Le>;
+
+/// A value stored in the target’s byte order.
+///
+/// This performs no conversions at all, but allows code that is generic over
+/// the endian types to also handle values in the native byte order.
+pub type NativeEndian<T> = Integer<T, Ne>;
+
+impl<T: Endian<T>, O: Order> Integer<T, O> {
+    /// The number of bytes of the stored value, i.e. `size_of::<T>()`, e.g.
+    /// for sizing buffers in code that is generic over `T`.
+    ///
+    /// ```
+    /// use byte_endian::BigEndian;
+    ///
+    /// let buffer = [0u8; BigEndian::<u32>::BYTE_LEN];
+    /// assert_eq!(buffer.len(), 4);
+    /// ```
+    pub const BYTE_LEN: usize = size_of::<T>();
+
+    #[inline]
+    pub fn new(value: T) -> Self {
+        Self(O::to_order(value), PhantomData)
+    }
+
+    /// Creates a new value from `value` in the target’s endianness, swapping
+    /// its bytes if the byte order of `Self` is not the target’s. Same as
+    /// `new`.
+    ///
+    /// ```
+    /// use byte_endian::BigEndian;
+    ///
+    /// let value = BigEndian::from_native(0x1234u16);
+    /// assert_eq!(value.to_bytes(), [0x12, 0x34]);
+    /// ```
+    #[inline]
+    pub fn from_native(value: T) -> Self {
+        Self::new(value)
+    }
+
+    /// Converts `self` from its byte order to the target’s endianness.
+    #[inline]
+    pub fn to_native(self) -> T {
+        O::from_order(self.0)
+    }
+
+    /// Same as `to_native`.
+    #[inline]
+    pub fn get(self) -> T {
+        self.to_native()
+    }
+
+    /// Replaces the stored value with `value`, converting it to the byte order
+    /// of `self`.
+    #[inline]
+    pub fn set(&mut self, value: T) {
+        // Overwrite the whole wrapper rather than borrowing the inner field.
+        *self = Self::new(value);
+    }
+
+    /// Creates a new value from `bits` as is, which are expected to already be
+    /// in the byte order of `Self`.
+    #[inline]
+    pub const fn from_bits(bits: T) -> Self {
+        Self(bits, PhantomData)
+    }
+
+    #[inline]
+    pub const fn to_bits(self) -> T {
+        self.0
+    }
+
+    /// Same as `to_bits`.
+    #[inline]
+    pub const fn into_bits(self) -> T {
+        self.0
+    }
+
+    /// Reverses the byte order of the stored bits, keeping the same type.
+    ///
+    /// This is useful for correcting a value whose byte order was mislabeled.
+    #[inline]
+    #[must_use]
+    pub fn swap_bytes(self) -> Self {
+        // Exactly one of `to_le` and `from_be` swaps, depending on the target.
+        Self(T::from_be(self.0.to_le()), PhantomData)
+    }
+
+    /// Sums the native values of `iter`, returning the native total.
+    #[inline]
+    pub fn sum_native(iter: impl Iterator<Item = Self>) -> T
+    where
+        T: Sum,
+    {
+        iter.map(Self::to_native).sum()
+    }
+
+    /// Performs a volatile read of the value at `ptr` and converts it to the
+    /// target’s endianness, without creating a reference to it (e.g. for
+    /// memory mapped registers).
+    ///
+    /// # Safety
+    ///
+    /// Same as [`ptr::read_volatile`].
+    #[inline]
+    pub unsafe fn read_volatile(ptr: *const Self) -> T {
+        // SAFETY: Guaranteed by the caller.
+        unsafe { ptr::read_volatile(ptr) }.to_native()
+    }
+
+    /// Converts `value` to the byte order of `Self` and performs a volatile
+    /// write of it to `ptr`, without creating a reference to it (e.g. for
+    /// memory mapped registers).
+    ///
+    /// # Safety
+    ///
+    /// Same as [`ptr::write_volatile`].
+    #[inline]
+    pub unsafe fn write_volatile(ptr: *mut Self, value: T) {
+        // SAFETY: Guaranteed by the caller.
+        unsafe { ptr::write_volatile(ptr, Self::new(value)) }
+    }
+
+    /// Returns an iterator over the native values in `range`, each converted
+    /// to the byte order of `Self`.
+    ///
+    /// This stands in for ranges of wrappers, as implementing the unstable
+    /// `Step` trait is not possible.
+    #[inline]
+    pub fn range(range: ops::Range<T>) -> Map<ops::Range<T>, fn(T) -> Self>
+    where
+        ops::Range<T>: Iterator<Item = T>,
+    {
+        range.map(Self::new)
+    }
+
+    /// Applies `f` to the native value and converts the result back to the
+    /// byte order of `self`.
+    #[inline]
+    #[must_use]
+    pub fn map(self, f: impl FnOnce(T) -> T) -> Self {
+        Self::new(f(self.to_native()))
+    }
+}
+
+impl<T: Endian<T> + PartialOrd, O: Order> Integer<T, O> {
+    /// Returns the value with the smaller native value, or `self` if they are
+    /// equal (or cannot be compared).
+    #[inline]
+    #[must_use]
+    pub fn min(self, other: Self) -> Self {
+        if other.to_native() < self.to_native() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the value with the larger native value, or `other` if they are
+    /// equal. Returns `self` if they cannot be compared.
+    #[inline]
+    #[must_use]
+    pub fn max(self, other: Self) -> Self {
+        if other.to_native() >= self.to_native() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Restricts the native value to the range `lo..=hi`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo` is greater than `hi`.
+    #[inline]
+    #[must_use]
+    pub fn clamp(self, lo: Self, hi: Self) -> Self {
+        assert!(
+            lo.to_native() <= hi.to_native(),
+            "lo must not be greater than hi"
+        );
+
+        if self.to_native() < lo.to_native() {
+            lo
+        } else if self.to_native() > hi.to_native() {
+            hi
+        } else {
+            self
+        }
+    }
+
+    /// Wraps `self` in [`Reverse`], which orders by the native value in
+    /// descending order, e.g. for sorting with `sort_by_key`.
+    ///
+    /// ```
+    /// use byte_endian::BigEndian;
+    ///
+    /// let mut values = [1u32, 3, 2].map(BigEndian::new);
+    /// values.sort_by_key(|value| value.reversed());
+    /// assert_eq!(values, [3, 2, 1].map(BigEndian::new));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn reversed(self) -> Reverse<Self> {
+        Reverse(self)
+    }
+}
+
+impl<T: Endian<T>, O: Order> From<T> for Integer<T, O> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Endian<T>, O: Order> From<&T> for Integer<T, O> {
+    #[inline]
+    fn from(value: &T) -> Self {
+        Self::new(*value)
+    }
+}
+
+/// Formats the native value, e.g. `BigEndian(4660)`.
+///
+/// The alternate form (`{:#?}`) also includes the stored bits, which can be
+/// combined with hex formatting (`{:#x?}`).
+impl<T: Endian<T> + fmt::Debug, O: Order> fmt::Debug for Integer<T, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.debug_struct(O::NAME)
+                .field("native", &self.to_native())
+                .field("bits", &self.0)
+                .finish()
+        } else {
+            f.debug_tuple(O::NAME).field(&self.to_native()).finish()
+        }
+    }
+}
+
+impl<T: Endian<T> + PartialEq, O: Order, P: Order> PartialEq<Integer<T, P>> for Integer<T, O> {
+    // Compare the native values rather than the stored ones, as the stored
+    // representation of a float might not be a meaningful float (e.g. it
+    // could be a NaN). This also allows comparing values stored in different
+    // byte orders.
+    #[inline]
+    fn eq(&self, other: &Integer<T, P>) -> bool {
+        self.to_native() == other.to_native()
+    }
+}
+
+impl<T: Endian<T> + Eq, O: Order> Eq for Integer<T, O> {}
+
+/// Compares the native value of `self` with `other`.
+///
+/// Note that this makes comparisons of the form `value == native.into()`
+/// ambiguous, as the conversion can now target both `Self` and `T`, so code
+/// that used them fails to compile with "type annotations needed". Compare
+/// with the native value directly instead, i.e. `value == native`.
+///
+/// ```
+/// use byte_endian::BigEndian;
+///
+/// assert!(BigEndian::new(12345u64) == 12345u64);
+/// ```
+///
+/// ```compile_fail,E0283
+/// use byte_endian::BigEndian;
+///
+/// assert!(BigEndian::new(12345u64) == 12345u64.into());
+/// ```
+impl<T: Endian<T> + PartialEq, O: Order> PartialEq<T> for Integer<T, O> {
+    #[inline]
+    fn eq(&self, other: &T) -> bool {
+        self.to_native() == *other
+    }
+}
+
+impl<T: Endian<T> + PartialOrd, O: Order> PartialOrd for Integer<T, O> {
+    // The stored bits might be byte swapped, so they have to be converted to
+    // native to compare by numeric value.
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.to_native().partial_cmp(&other.to_native())
+    }
+}
+
+impl<T: Endian<T> + Ord, O: Order> Ord for Integer<T, O> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_native().cmp(&other.to_native())
+    }
+}
+
+impl<T: Endian<T> + Hash, O: Order> Hash for Integer<T, O> {
+    // Hash the native value, so that the hash does not depend on the byte
+    // order (of either the wrapper or the target) and matches the hash of the
+    // bare native value.
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_native().hash(state);
+    }
+}
+
+// Accumulate the native values and only convert the result back to the byte
+// order of `Self` once.
+impl<T: Endian<T> + Sum, O: Order> Sum for Integer<T, O> {
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self::new(iter.map(Self::to_native).sum())
+    }
+}
+
+impl<'a, T: Endian<T> + Sum, O: Order> Sum<&'a Self> for Integer<T, O> {
+    #[inline]
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        Self::new(iter.map(|value| value.to_native()).sum())
+    }
+}
+
+impl<T: Endian<T> + Product, O: Order> Product for Integer<T, O> {
+    #[inline]
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self::new(iter.map(Self::to_native).product())
+    }
+}
+
+impl<'a, T: Endian<T> + Product, O: Order> Product<&'a Self> for Integer<T, O> {
+    #[inline]
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        Self::new(iter.map(|value| value.to_native()).product())
+    }
+}
+
+impl<T: Endian<T>, const N: usize> sealed::Sealed for [T; N] {}
+
+/// Converts each element of the array independently, so `N = 0` is a no-op.
+impl<T: Endian<T>, const N: usize> Endian<[T; N]> for [T; N] {
+    fn to_be(&self) -> [T; N] {
+        self.map(|element| element.to_be())
+    }
+
+    fn to_le(&self) -> [T; N] {
+        self.map(|element| element.to_le())
+    }
+
+    fn from_be(value: [T; N]) -> [T; N] {
+        value.map(T::from_be)
+    }
+
+    fn from_le(value: [T; N]) -> [T; N] {
+        value.map(T::from_le)
+    }
+}
+
+impl<T: Endian<T>, O: Order, const N: usize> From<Integer<[T; N], O>> for [T; N] {
+    #[inline]
+    fn from(value: Integer<[T; N], O>) -> Self {
+        value.to_native()
+    }
+}
+
+/// Reads the elements from consecutive bytes, each of which is expected to be
+/// in the byte order of `Self`.
+///
+/// Fails if the length of `bytes` is not `N * size_of::<T>()`.
+impl<T: Endian<T>, O: Order, const N: usize> TryFrom<&[u8]> for Integer<[T; N], O>
+where
+    Integer<T, O>: ReadFromPrefix,
+{
+    type Error = TryFromSliceError;
+
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        match bytes.len().cmp(&size_of::<Self>()) {
+            Ordering::Less => return Err(TryFromSliceError::TooShort),
+            Ordering::Greater => return Err(TryFromSliceError::TooLong),
+            Ordering::Equal => {}
+        }
+
+        let mut rest = bytes;
+        Ok(Self::from_bits(core::array::from_fn(|_| {
+            let Some((element, tail)) = Integer::<T, O>::read_from_prefix(rest) else {
+                unreachable!()
+            };
+            rest = tail;
+            element.to_bits()
+        })))
+    }
+}
+
+impl<T: Endian<T>, O: Order, const N: usize> Integer<[T; N], O> {
+    /// Returns the native value of the element at `index`, converting only
+    /// that element, or `None` if `index` is out of bounds.
+    ///
+    /// This stands in for indexing, as [`Index`](core::ops::Index) would have
+    /// to return a reference to a native value that is not stored.
+    ///
+    /// ```
+    /// use byte_endian::BigEndian;
+    ///
+    /// let value = BigEndian::new([1u16, 2, 3]);
+    /// assert_eq!(value.element(1), Some(2));
+    /// assert_eq!(value.element(3), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn element(self, index: usize) -> Option<T> {
+        self.0.get(index).copied().map(O::from_order)
+    }
+
+    /// Converts every element to the target’s endianness, returning the
+    /// native array. Same as `to_native`.
+    ///
+    /// ```
+    /// use byte_endian::LittleEndian;
+    ///
+    /// let value = LittleEndian::new([1u32, 2, 3]);
+    /// assert_eq!(value.to_native_array(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_native_array(self) -> [T; N] {
+        self.to_native()
+    }
+}
+
+/// Converts a big endian value to little endian.
+///
+/// Exactly one byte swap of the stored bits is performed, regardless of the
+/// target’s endianness.
+impl<T: Endian<T>> From<BigEndian<T>> for LittleEndian<T> {
+    #[inline]
+    fn from(value: BigEndian<T>) -> Self {
+        // On a little endian target only `from_be` swaps and on a big endian
+        // target only `to_le` swaps.
+        Self(T::from_be(value.0).to_le(), PhantomData)
+    }
+}
+
+/// Converts a little endian value to big endian.
+///
+/// Exactly one byte swap of the stored bits is performed, regardless of the
+/// target’s endianness.
+impl<T: Endian<T>> From<LittleEndian<T>> for BigEndian<T> {
+    #[inline]
+    fn from(value: LittleEndian<T>) -> Self {
+        // On a little endian target only `to_be` swaps and on a big endian
+        // target only `from_le` swaps.
+        Self(T::from_le(value.0).to_be(), PhantomData)
+    }
+}
+
+impl<T: Endian<T>> BigEndian<T> {
+    /// Converts `self` to little endian, with exactly one byte swap of the
+    /// stored bits.
+    #[inline]
+    #[must_use]
+    pub fn to_little(self) -> LittleEndian<T> {
+        LittleEndian::from(self)
+    }
+}
+
+impl<T: Endian<T>> LittleEndian<T> {
+    /// Converts `self` to big endian, with exactly one byte swap of the stored
+    /// bits.
+    #[inline]
+    #[must_use]
+    pub fn to_big(self) -> BigEndian<T> {
+        BigEndian::from(self)
+    }
+}
+
+impl<O: Order> Integer<u32, O> {
+    /// Splits `self` into the two 16-bit halves that overlay its stored bytes.
+    ///
+    /// The halves are in storage order, so for big endian the first one is the
+    /// most significant half and for little endian it is the least significant
+    /// one.
+    #[inline]
+    #[must_use]
+    pub fn split_u16(self) -> [Integer<u16, O>; 2] {
+        let [a, b, c, d] = self.to_bytes();
+        [
+            Integer::<u16, O>::from_bytes([a, b]),
+            Integer::<u16, O>::from_bytes([c, d]),
+        ]
+    }
+
+    /// Joins two 16-bit halves in storage order, the inverse of
+    /// [`split_u16`](Self::split_u16).
+    #[inline]
+    #[must_use]
+    pub fn join_u16(halves: [Integer<u16, O>; 2]) -> Self {
+        let [a, b] = halves[0].to_bytes();
+        let [c, d] = halves[1].to_bytes();
+        Self::from_bytes([a, b, c, d])
+    }
+}
+
+/// Converts an IPv4 address stored in network byte order (big endian).
+impl From<BigEndian<u32>> for Ipv4Addr {
+    #[inline]
+    fn from(value: BigEndian<u32>) -> Self {
+        Ipv4Addr::from(value.to_bytes())
+    }
+}
+
+/// Converts an IPv4 address to network byte order (big endian).
+impl From<Ipv4Addr> for BigEndian<u32> {
+    #[inline]
+    fn from(value: Ipv4Addr) -> Self {
+        Self::from_bytes(value.octets())
+    }
+}
+
+/// Converts an IPv6 address stored in network byte order (big endian).
+impl From<BigEndian<u128>> for Ipv6Addr {
+    #[inline]
+    fn from(value: BigEndian<u128>) -> Self {
+        Ipv6Addr::from(value.to_bytes())
+    }
+}
+
+/// Converts an IPv6 address to network byte order (big endian).
+impl From<Ipv6Addr> for BigEndian<u128> {
+    #[inline]
+    fn from(value: Ipv6Addr) -> Self {
+        Self::from_bytes(value.octets())
+    }
+}
+
+impl_fmt!(Display, LowerHex, UpperHex, Binary, Octal, LowerExp, UpperExp);
+
+impl_ops!(@native
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign;
+    Rem, rem, RemAssign, rem_assign
+);
+
+impl_ops!(@bits
+    BitAnd, bitand, BitAndAssign, bitand_assign;
+    BitOr, bitor, BitOrAssign, bitor_assign;
+    BitXor, bitxor, BitXorAssign, bitxor_assign
+);
+
+impl_ops!(@shift
+    Shl, shl, ShlAssign, shl_assign;
+    Shr, shr, ShrAssign, shr_assign
+);
+
+impl_traits!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_traits!(@unsigned_impl u8, u16, u32, u64, u128, usize);
+impl_traits!(@signed_impl i8, i16, i32, i64, i128, isize);
+impl_traits!(@from
+    u8 => u16, u32, u64, u128, usize, i16, i32, i64, i128, isize;
+    u16 => u32, u64, u128, usize, i32, i64, i128;
+    u32 => u64, u128, i64, i128;
+    u64 => u128, i128;
+    i8 => i16, i32, i64, i128, isize;
+    i16 => i32, i64, i128, isize;
+    i32 => i64, i128;
+    i64 => i128
+);
+impl_traits!(@cast
+    i8 <=> u8, i16 <=> u16, i32 <=> u32, i64 <=> u64, i128 <=> u128, isize <=> usize
+);
+impl_traits!(@try_from
+    u16 => u8; u32 => u8, u16; u64 => u8, u16, u32; u128 => u8, u16, u32, u64;
+    i16 => i8; i32 => i8, i16; i64 => i8, i16, i32; i128 => i8, i16, i32, i64
+);
+impl_traits!(@float f32 => u32, f64 => u64);
+impl_traits!(@nonzero
+    NonZeroU8 => u8, NonZeroU16 => u16, NonZeroU32 => u32, NonZeroU64 => u64,
+    NonZeroU128 => u128, NonZeroUsize => usize,
+    NonZeroI8 => i8, NonZeroI16 => i16, NonZeroI32 => i32, NonZeroI64 => i64,
+    NonZeroI128 => i128, NonZeroIsize => isize
+);
+
+// A `bool` is a single byte, so it has no byte order. It still implements
+// `Endian` so that it can be used alongside the other wrapped types.
+impl sealed::Sealed for bool {}
+
+impl Endian<bool> for bool {
+    fn to_be(&self) -> bool {
+        *self
+    }
+
+    fn to_le(&self) -> bool {
+        *self
+    }
+
+    fn from_be(value: bool) -> bool {
+        value
+    }
+
+    fn from_le(value: bool) -> bool {
+        value
+    }
+}
+
+impl_traits!(@from_wrapper bool);
+impl_traits!(@layout bool);
+impl_traits!(@type_name bool);
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::*;
+    use std::format;
+    use std::hash::DefaultHasher;
+    use std::vec::Vec;
+
+    /// A fixed capacity `fmt::Write` buffer, to check that formatting does not
+    /// need to allocate.
+    pub(crate) struct Buf<const N: usize> {
+        bytes: [u8; N],
+        len: usize,
+    }
+
+    impl<const N: usize> Buf<N> {
+        pub(crate) fn new() -> Self {
+            Self {
+                bytes: [0; N],
+                len: 0,
+            }
+        }
+
+        pub(crate) fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+        }
+    }
+
+    impl<const N: usize> fmt::Write for Buf<N> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let rest = &mut self.bytes[self.len..];
+            if s.len() > rest.len() {
+                return Err(fmt::Error);
+            }
+            rest[..s.len()].copy_from_slice(s.as_bytes());
+            self.len += s.len();
+            Ok(())
+        }
+    }
+
+    #[test]
+    #[allow(clippy::unreadable_literal)]
+    fn inside_packed() {
+        // This tests that no references are created by calling any of the methods on
+        // the endian types. Since, creating references to a packed field (which is not
+        // aligned) is undefined behaviour (`E0793`).
+        #[repr(C, packed)]
+        struct Packet(BigEndian<u64>, LittleEndian<u64>);
+
+        let packet = Packet(0xfe.into(), 0xfe.into());
+
+        assert_eq!(packet.0.to_native(), 0xfe);
+        if cfg!(target_endian = "big") {
+            assert_eq!(packet.0.to_bits(), 0xfeu64);
+        } else {
+            assert_eq!(packet.0.to_bits(), 0xfe00000000000000u64);
+        }
+
+        assert_eq!(packet.1.to_native(), 0xfe);
+        if cfg!(target_endian = "big") {
+            assert_eq!(packet.1.to_bits(), 0xfe00000000000000u64);
+        } else {
+            assert_eq!(packet.1.to_bits(), 0xfeu64);
+        }
+    }
+
+    #[test]
+    fn new_to_native() {
+        let be_value = BigEndian::new(12345u64);
+        // Before `PartialEq<T>`, this compared with `12345u64.into()`, which is
+        // ambiguous now. The native value is compared directly instead.
+        assert_eq!(be_value, 12345u64);
+        assert_eq!(be_value, BigEndian::from(12345u64));
+        assert_eq!(be_value.to_native(), 12345u64);
+
+        let be_native: u64 = be_value.into();
+        assert_eq!(be_native, 12345u64);
+
+        let le_value = LittleEndian::new(12345u64);
+        assert_eq!(le_value, 12345u64);
+        assert_eq!(le_value, LittleEndian::from(12345u64));
+        assert_eq!(le_value.to_native(), 12345u64);
+
+        let native: u64 = le_value.into();
+        assert_eq!(native, 12345u64);
+    }
+
+    #[test]
+    #[allow(clippy::unreadable_literal)]
+    fn new_to_bits() {
+        let value = BigEndian::new(0xfeu64);
+        if cfg!(target_endian = "big") {
+            assert_eq!(value.to_bits(), 0xfeu64);
+        } else {
+            assert_eq!(value.to_bits(), 0xfe00000000000000u64);
+        }
+
+        let value = LittleEndian::new(0xfeu64);
+        if cfg!(target_endian = "big") {
+            assert_eq!(value.to_bits(), 0xfe00000000000000u64);
+        } else {
+            assert_eq!(value.to_bits(), 0xfeu64);
+        }
+
+        // The bytes of the stored bits in memory do not depend on the target, so
+        // these hold on both big and little endian targets.
+        let value = BigEndian::new(0xfeu64);
+        assert_eq!(value.to_bits().to_ne_bytes(), [0, 0, 0, 0, 0, 0, 0, 0xfe]);
+        let value = LittleEndian::new(0xfeu64);
+        assert_eq!(value.to_bits().to_ne_bytes(), [0xfe, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn signed_round_trip() {
+        let value = BigEndian::new(-1i32);
+        assert_eq!(value.to_native(), -1i32);
+        assert_eq!(value.to_bits(), -1i32);
+
+        let value = LittleEndian::new(i64::MIN);
+        assert_eq!(value.to_native(), i64::MIN);
+
+        let native: i16 = BigEndian::new(-12345i16).into();
+        assert_eq!(native, -12345i16);
+    }
+
+    #[test]
+    fn signed_to_bits() {
+        // The sign bit lives in the most significant byte, so it should end up in
+        // the first stored byte for big endian and in the last one for little endian.
+        let value = BigEndian::new(-2i32);
+        assert_eq!(value.to_bits().to_ne_bytes(), [0xff, 0xff, 0xff, 0xfe]);
+
+        let value = LittleEndian::new(-2i32);
+        assert_eq!(value.to_bits().to_ne_bytes(), [0xfe, 0xff, 0xff, 0xff]);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn float_round_trip() {
+        assert_eq!(LittleEndian::new(1.5f64).to_native(), 1.5);
+        assert_eq!(BigEndian::new(-1.5f32).to_native(), -1.5);
+        assert_eq!(BigEndian::new(1.5f64), BigEndian::new(1.5f64));
+
+        // NaN payloads and the sign of zero have to survive the round trip.
+        let nan = f64::from_bits(0x7ff8_0000_dead_beef);
+        assert_eq!(BigEndian::new(nan).to_native().to_bits(), nan.to_bits());
+        assert_eq!(LittleEndian::new(nan).to_native().to_bits(), nan.to_bits());
+
+        let zero = BigEndian::new(-0.0f32).to_native();
+        assert_eq!(zero.to_bits(), (-0.0f32).to_bits());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn float_from_bytes() {
+        let value = BigEndian::<f64>::from_bytes([0x3f, 0xf8, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(value.to_native(), 1.5);
+
+        let value = LittleEndian::<f32>::from_bytes([0, 0, 0xc0, 0x3f]);
+        assert_eq!(value.to_native(), 1.5);
+    }
+
+    #[test]
+    fn new_const() {
+        static MAGIC: BigEndian<u32> = BigEndian::<u32>::new_const(0xcafe_babe);
+        const OFFSET: LittleEndian<i16> = LittleEndian::<i16>::new_const(-42);
+
+        assert_eq!(MAGIC, BigEndian::new(0xcafe_babe));
+        assert_eq!(MAGIC.to_native(), 0xcafe_babe);
+        assert_eq!(MAGIC.to_bits().to_ne_bytes(), [0xca, 0xfe, 0xba, 0xbe]);
+
+        assert_eq!(OFFSET, LittleEndian::new(-42));
+        assert_eq!(OFFSET.to_native(), -42);
+    }
+
+    #[test]
+    fn add() {
+        let value = LittleEndian::new(0x1234u16) + LittleEndian::new(0x0101);
+        assert_eq!(value.to_native(), 0x1335);
+        assert_eq!(value.to_bits().to_ne_bytes(), [0x35, 0x13]);
+
+        let mut value = BigEndian::new(0x1234u16);
+        value += BigEndian::new(0x0101);
+        assert_eq!(value.to_native(), 0x1335);
+        assert_eq!(value.to_bits().to_ne_bytes(), [0x13, 0x35]);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = BigEndian::new(1000u32);
+        let b = BigEndian::new(7u32);
+
+        assert_eq!((a - b).to_native(), 993);
+        assert_eq!((a * b).to_native(), 7000);
+        assert_eq!((a / b).to_native(), 142);
+        assert_eq!((a % b).to_native(), 6);
+
+        let mut value = LittleEndian::new(-20i64);
+        value -= LittleEndian::new(5);
+        value *= LittleEndian::new(-4);
+        value /= LittleEndian::new(3);
+        value %= LittleEndian::new(7);
+        assert_eq!(value.to_native(), ((-20i64 - 5) * -4 / 3) % 7);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "overflow")]
+    fn add_overflow() {
+        let _ = LittleEndian::new(250u8) + LittleEndian::new(10);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "overflow")]
+    fn sub_overflow() {
+        let _ = BigEndian::new(5u8) - BigEndian::new(6);
+    }
+
+    #[test]
+    #[should_panic(expected = "divide by zero")]
+    fn div_by_zero() {
+        let _ = BigEndian::new(5u32) / BigEndian::new(0);
+    }
+
+    #[test]
+    fn bitwise() {
+        let a = 0x1234_abcdu32;
+        let b = 0x00ff_f00fu32;
+
+        let (be_a, be_b) = (BigEndian::new(a), BigEndian::new(b));
+        assert_eq!((be_a & be_b).to_native(), a & b);
+        assert_eq!((be_a | be_b).to_native(), a | b);
+        assert_eq!((be_a ^ be_b).to_native(), a ^ b);
+        assert_eq!((!be_a).to_native(), !a);
+
+        let mut value = LittleEndian::new(a);
+        value &= LittleEndian::new(b);
+        assert_eq!(value, LittleEndian::new(a & b));
+        value |= LittleEndian::new(0xf000_0000);
+        assert_eq!(value, LittleEndian::new((a & b) | 0xf000_0000));
+        value ^= LittleEndian::new(b);
+        assert_eq!(value, LittleEndian::new(((a & b) | 0xf000_0000) ^ b));
+    }
+
+    #[test]
+    fn shift() {
+        let value = BigEndian::new(0x1234_5678u32) << 8;
+        assert_eq!(value.to_native(), 0x1234_5678u32 << 8);
+        assert_eq!(value.to_bits().to_ne_bytes(), [0x34, 0x56, 0x78, 0x00]);
+
+        let value = LittleEndian::new(0x1234_5678u32) >> 8;
+        assert_eq!(value.to_native(), 0x1234_5678u32 >> 8);
+        assert_eq!(value.to_bits().to_ne_bytes(), [0x56, 0x34, 0x12, 0x00]);
+
+        let mut value = BigEndian::new(0x0102u16);
+        value <<= 4;
+        assert_eq!(value.to_native(), 0x1020);
+        value >>= 8;
+        assert_eq!(value.to_native(), 0x10);
+    }
+
+    #[test]
+    fn ordering() {
+        // 0x0100 is stored as [0x01, 0x00] and 0x00ff as [0x00, 0xff], so sorting by
+        // the stored bits on a little endian target would give the wrong order.
+        let mut values: Vec<BigEndian<u16>> = [0x00ff, 0x0100, 0x0001, 0xff00]
+            .into_iter()
+            .map(BigEndian::new)
+            .collect();
+        values.sort();
+
+        let natives: Vec<u16> = values.into_iter().map(BigEndian::to_native).collect();
+        assert_eq!(natives, [0x0001, 0x00ff, 0x0100, 0xff00]);
+
+        assert!(LittleEndian::new(-1i32) < LittleEndian::new(1));
+        assert!(BigEndian::new(1.5f32) > BigEndian::new(-2.0));
+        assert_eq!(
+            BigEndian::new(f64::NAN).partial_cmp(&BigEndian::new(1.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn formatting() {
+        assert_eq!(format!("{}", BigEndian::new(1234u32)), "1234");
+        assert_eq!(format!("{:>6}", LittleEndian::new(-12i16)), "   -12");
+        assert_eq!(format!("{:#06x}", BigEndian::new(0xabu16)), "0x00ab");
+        assert_eq!(format!("{:#06x}", LittleEndian::new(0xabu16)), "0x00ab");
+        assert_eq!(format!("{:X}", BigEndian::new(0xabcd_u32)), "ABCD");
+        assert_eq!(format!("{:#b}", LittleEndian::new(5u8)), "0b101");
+        assert_eq!(format!("{:o}", BigEndian::new(8u64)), "10");
+        assert_eq!(format!("{:.1}", BigEndian::new(1.25f32)), "1.2");
+        assert_eq!(format!("{:e}", LittleEndian::new(1500.0f64)), "1.5e3");
+    }
+
+    #[test]
+    fn to_bytes() {
+        assert_eq!(BigEndian::<u16>::new(0x1234).to_bytes(), [0x12, 0x34]);
+        assert_eq!(LittleEndian::<u16>::new(0x1234).to_bytes(), [0x34, 0x12]);
+        assert_eq!(BigEndian::<u8>::new(0x12).to_bytes(), [0x12]);
+        assert_eq!(
+            BigEndian::<i64>::new(-2).to_bytes(),
+            [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe]
+        );
+        assert_eq!(
+            LittleEndian::<f32>::new(1.5).to_bytes(),
+            [0x00, 0x00, 0xc0, 0x3f]
+        );
+    }
+
+    #[test]
+    fn from_bytes() {
+        let value = BigEndian::<u32>::from_bytes([0x12, 0x34, 0x56, 0x78]);
+        assert_eq!(value.to_native(), 0x1234_5678);
+        assert_eq!(value.to_bytes(), [0x12, 0x34, 0x56, 0x78]);
+
+        let value = LittleEndian::<u32>::from_bytes([0x12, 0x34, 0x56, 0x78]);
+        assert_eq!(value.to_native(), 0x7856_3412);
+
+        let value = BigEndian::<f64>::from_bytes([0x3f, 0xf8, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(value, BigEndian::new(1.5));
+    }
+
+    #[test]
+    fn native_endian() {
+        const VALUE: NativeEndian<u16> = NativeEndian::<u16>::new_const(7);
+
+        let value = NativeEndian::new(0x1234_5678u32);
+        assert_eq!(value.to_native(), 0x1234_5678);
+        assert_eq!(value.to_bits(), 0x1234_5678);
+        assert_eq!(value.to_bytes(), 0x1234_5678u32.to_ne_bytes());
+        assert_eq!(value, NativeEndian::from(0x1234_5678));
+
+        let native: i8 = NativeEndian::new(-3i8).into();
+        assert_eq!(native, -3);
+
+        assert_eq!((VALUE + NativeEndian::new(1)).to_native(), 8);
+        assert_eq!(NativeEndian::new(7), VALUE);
+    }
+
+    #[test]
+    fn big_little_conversion() {
+        let big = BigEndian::new(0x1234u16);
+        let little = LittleEndian::from(big);
+        assert_eq!(little.to_native(), 0x1234);
+        assert_eq!(little.to_bits(), big.to_bits().swap_bytes());
+        assert_eq!(little.to_bytes(), [0x34, 0x12]);
+
+        let big = BigEndian::from(LittleEndian::new(-2i32));
+        assert_eq!(big.to_native(), -2);
+        assert_eq!(big.to_bytes(), [0xff, 0xff, 0xff, 0xfe]);
+
+        let little: LittleEndian<f64> = BigEndian::new(1.5f64).into();
+        assert_eq!(little, LittleEndian::new(1.5));
+    }
+
+    #[test]
+    fn swap_bytes() {
+        let value = BigEndian::new(0x1234_5678u32).swap_bytes();
+        assert_eq!(value.to_native(), 0x1234_5678u32.swap_bytes());
+        assert_eq!(value.to_bytes(), [0x78, 0x56, 0x34, 0x12]);
+
+        let value = LittleEndian::new(0x1234_5678u32).swap_bytes();
+        assert_eq!(value.to_native(), 0x1234_5678u32.swap_bytes());
+        assert_eq!(value.to_bytes(), [0x12, 0x34, 0x56, 0x78]);
+
+        let value = NativeEndian::new(-2i16).swap_bytes();
+        assert_eq!(value.to_native(), (-2i16).swap_bytes());
+
+        assert_eq!(BigEndian::new(0xabu8).swap_bytes().to_native(), 0xab);
+        assert_eq!(
+            BigEndian::new(7u64).swap_bytes().swap_bytes().to_native(),
+            7
+        );
+    }
+
+    #[test]
+    fn as_bytes() {
+        let value = BigEndian::new(0x1234_5678u32);
+        assert_eq!(value.as_ref(), [0x12, 0x34, 0x56, 0x78]);
+
+        let value = LittleEndian::new(0x1234_5678u32);
+        assert_eq!(value.as_ref(), [0x78, 0x56, 0x34, 0x12]);
+
+        let mut value = BigEndian::new(0u16);
+        value.as_mut().copy_from_slice(&[0xab, 0xcd]);
+        assert_eq!(value.to_native(), 0xabcd);
+        assert_eq!(value.as_ref().len(), 2);
+
+        assert_eq!(LittleEndian::new(1.5f64).as_ref().len(), 8);
+    }
+
+    #[test]
+    fn nonzero() {
+        assert_eq!(size_of::<Option<BigEndian<NonZeroU32>>>(), 4);
+        assert_eq!(size_of::<Option<LittleEndian<NonZeroI64>>>(), 8);
+
+        let id = NonZeroU32::new(0x1234_5678).unwrap();
+        let value = BigEndian::new(id);
+        assert_eq!(value.to_native(), id);
+        assert_eq!(
+            value.to_bits().get().to_ne_bytes(),
+            [0x12, 0x34, 0x56, 0x78]
+        );
+
+        let id = NonZeroI16::new(-2).unwrap();
+        let native: NonZeroI16 = LittleEndian::new(id).into();
+        assert_eq!(native, id);
+
+        // A value whose low bytes are all zero still has to round trip.
+        let id = NonZeroU64::new(1 << 56).unwrap();
+        assert_eq!(LittleEndian::new(id).to_native(), id);
+        assert_eq!(BigEndian::new(id).to_native(), id);
+    }
+
+    #[test]
+    fn arrays() {
+        let value = BigEndian::new([0x0102u16; 4]);
+        for element in value.to_bits() {
+            assert_eq!(element.to_ne_bytes(), [0x01, 0x02]);
+        }
+        assert_eq!(value.to_native(), [0x0102; 4]);
+
+        let value = LittleEndian::new([0x0102u16, 0x0304]);
+        assert_eq!(value.to_bits()[0].to_ne_bytes(), [0x02, 0x01]);
+        assert_eq!(value.to_bits()[1].to_ne_bytes(), [0x04, 0x03]);
+
+        let native: [u16; 2] = value.into();
+        assert_eq!(native, [0x0102, 0x0304]);
+
+        let value = BigEndian::new([0x0102u16, 0x0304, 0xfffe, 0]);
+        assert_eq!(value.element(0), Some(0x0102));
+        assert_eq!(value.element(2), Some(0xfffe));
+        assert_eq!(value.element(3), Some(0));
+        assert_eq!(value.element(4), None);
+        assert_eq!(
+            LittleEndian::new([0x0102u16, 0x0304]).element(1),
+            Some(0x0304)
+        );
+
+        let empty = BigEndian::new([0u32; 0]);
+        assert_eq!(empty.to_native(), [0u32; 0]);
+        assert_eq!(empty.element(0), None);
+        assert_eq!(empty.to_native_array(), [0u32; 0]);
+
+        let value = LittleEndian::new([0x0102_0304u32, 0, u32::MAX]);
+        let native = value.to_native_array();
+        for (i, &element) in native.iter().enumerate() {
+            assert_eq!(value.element(i), Some(element));
+        }
+        assert_eq!(native, [0x0102_0304, 0, u32::MAX]);
+    }
+
+    #[test]
+    fn array_try_from_slice() {
+        let bytes: Vec<u8> = (0..16).collect();
+
+        let value = BigEndian::<[u16; 8]>::try_from(&bytes[..]).unwrap();
+        assert_eq!(value.element(0), Some(0x0001));
+        assert_eq!(value.element(7), Some(0x0e0f));
+        assert_eq!(
+            value.to_native(),
+            [0x0001, 0x0203, 0x0405, 0x0607, 0x0809, 0x0a0b, 0x0c0d, 0x0e0f]
+        );
+
+        let value = LittleEndian::<[u32; 2]>::try_from(&bytes[..8]).unwrap();
+        assert_eq!(value.to_native(), [0x0302_0100, 0x0706_0504]);
+
+        assert_eq!(
+            BigEndian::<[u16; 8]>::try_from(&bytes[..15]),
+            Err(TryFromSliceError::TooShort)
+        );
+        assert_eq!(
+            BigEndian::<[u16; 4]>::try_from(&bytes[..]),
+            Err(TryFromSliceError::TooLong)
+        );
+        assert!(BigEndian::<[u16; 0]>::try_from(&bytes[..0]).is_ok());
+        assert_eq!(size_of::<BigEndian<[u32; 0]>>(), 0);
+    }
+
+    #[test]
+    fn read_from() {
+        let bytes = [0x12, 0x34, 0x56, 0x78, 0x9a];
+
+        let value = BigEndian::<u32>::read_from(&bytes).unwrap();
+        assert_eq!(value.to_native(), 0x1234_5678);
+
+        let (value, rest) = LittleEndian::<u16>::read_from_prefix(&bytes).unwrap();
+        assert_eq!(value.to_native(), 0x3412);
+        assert_eq!(rest, [0x56, 0x78, 0x9a]);
+
+        let (value, rest) = BigEndian::<u8>::read_from_prefix(&bytes[4..]).unwrap();
+        assert_eq!(value.to_native(), 0x9a);
+        assert!(rest.is_empty());
+
+        assert_eq!(BigEndian::<u64>::read_from(&bytes), None);
+        assert_eq!(LittleEndian::<u32>::read_from_prefix(&bytes[2..]), None);
+        assert_eq!(BigEndian::<u8>::read_from(&[]), None);
+    }
+
+    #[test]
+    fn write_to() {
+        let mut out = [0u8; 4];
+        assert_eq!(BigEndian::new(0x1234_5678u32).write_to(&mut out), Some(()));
+        assert_eq!(out, [0x12, 0x34, 0x56, 0x78]);
+
+        let mut out = [0xffu8; 5];
+        assert_eq!(LittleEndian::new(0x1234u16).write_to(&mut out), Some(()));
+        assert_eq!(out, [0x34, 0x12, 0xff, 0xff, 0xff]);
+
+        let mut out = [0u8; 3];
+        assert_eq!(BigEndian::new(0x1234_5678u32).write_to(&mut out), None);
+        assert_eq!(out, [0; 3]);
+    }
+
+    #[test]
+    fn get_set() {
+        let mut value = BigEndian::new(1u32);
+        assert_eq!(value.get(), 1);
+        value.set(0x1234_5678);
+        assert_eq!(value.get(), 0x1234_5678);
+        assert_eq!(value.to_bytes(), [0x12, 0x34, 0x56, 0x78]);
+
+        let mut value = LittleEndian::new(1i16);
+        value.set(-2);
+        assert_eq!(value.get(), -2);
+    }
+
+    #[test]
+    fn set_inside_packed() {
+        #[repr(C, packed)]
+        struct Packet(u8, BigEndian<u32>);
+
+        // Fields of a packed struct cannot be borrowed, so they are copied out,
+        // updated and written back as a whole.
+        let mut packet = Packet(0, BigEndian::new(1));
+        let mut field = packet.1;
+        field.set(0xcafe_babe);
+        packet.1 = field;
+        assert_eq!(packet.1.get(), 0xcafe_babe);
+        assert_eq!(packet.0, 0);
+    }
+
+    fn hash_of<T: Hash>(value: T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn hash() {
+        let native = 0x1234_5678u32;
+        assert_eq!(hash_of(BigEndian::new(native)), hash_of(native));
+        assert_eq!(hash_of(LittleEndian::new(native)), hash_of(native));
+        assert_eq!(hash_of(NativeEndian::new(native)), hash_of(native));
+        assert_eq!(
+            hash_of(BigEndian::new(native)),
+            hash_of(LittleEndian::new(native))
+        );
+        assert_ne!(hash_of(BigEndian::new(native)), hash_of(BigEndian::new(1)));
+    }
+
+    #[test]
+    fn checked() {
+        let a = BigEndian::new(200u8);
+        assert_eq!(a.checked_add(BigEndian::new(55)), Some(BigEndian::new(255)));
+        assert_eq!(a.checked_add(BigEndian::new(56)), None);
+        assert_eq!(a.checked_sub(BigEndian::new(201)), None);
+        assert_eq!(a.checked_mul(BigEndian::new(2)), None);
+        assert_eq!(a.checked_div(BigEndian::new(0)), None);
+
+        let a = LittleEndian::new(-0x1234_5678i32);
+        assert_eq!(
+            a.checked_sub(LittleEndian::new(1)),
+            Some(LittleEndian::new(-0x1234_5679))
+        );
+        assert_eq!(
+            a.checked_div(LittleEndian::new(-2)),
+            Some(LittleEndian::new(0x91a_2b3c))
+        );
+        assert_eq!(
+            LittleEndian::new(i32::MIN).checked_div(LittleEndian::new(-1)),
+            None
+        );
+        assert_eq!(a.checked_mul(LittleEndian::new(0x100)), None);
+    }
+
+    #[test]
+    fn wrapping_saturating() {
+        let max = LittleEndian::new(255u8);
+        assert_eq!(max.wrapping_add(LittleEndian::new(1)).to_native(), 0);
+        assert_eq!(
+            LittleEndian::new(0u8)
+                .wrapping_sub(LittleEndian::new(1))
+                .to_native(),
+            255
+        );
+        assert_eq!(max.wrapping_mul(LittleEndian::new(2)).to_native(), 254);
+
+        assert_eq!(
+            max.saturating_add(LittleEndian::new(1)).to_native(),
+            u8::MAX
+        );
+        assert_eq!(
+            BigEndian::new(i16::MIN)
+                .saturating_sub(BigEndian::new(1))
+                .to_native(),
+            i16::MIN
+        );
+        assert_eq!(
+            BigEndian::new(0x1_0000u32).saturating_mul(BigEndian::new(0x1_0000)),
+            BigEndian::new(u32::MAX)
+        );
+        assert_eq!(
+            BigEndian::new(0x1_0000u32).wrapping_mul(BigEndian::new(0x1_0001)),
+            BigEndian::new(0x1_0000)
+        );
+    }
+
+    fn encode<O: Order>(value: u32) -> (Integer<u32, O>, [u8; 4]) {
+        let value = Integer::<u32, O>::new(value) + Integer::new(1);
+        (value, value.to_bytes())
+    }
+
+    #[test]
+    fn generic_order() {
+        let (value, bytes) = encode::<Be>(0x1234_5677);
+        assert_eq!(value, BigEndian::new(0x1234_5678));
+        assert_eq!(bytes, [0x12, 0x34, 0x56, 0x78]);
+
+        let (value, bytes) = encode::<Le>(0x1234_5677);
+        assert_eq!(value, LittleEndian::new(0x1234_5678));
+        assert_eq!(bytes, [0x78, 0x56, 0x34, 0x12]);
+
+        let (value, bytes) = encode::<Ne>(0x1234_5677);
+        assert_eq!(value.to_native(), 0x1234_5678);
+        assert_eq!(bytes, 0x1234_5678u32.to_ne_bytes());
+
+        assert_eq!(format!("{:?}", BigEndian::new(0u8)), "BigEndian(0)");
+        assert_eq!(format!("{:?}", NativeEndian::new(1u8)), "NativeEndian(1)");
+    }
+
+    #[test]
+    fn try_from_slice() {
+        let bytes: &[u8] = &[0x12, 0x34, 0x56, 0x78];
+
+        let value = BigEndian::<u32>::try_from(bytes).unwrap();
+        assert_eq!(value.to_native(), 0x1234_5678);
+        let value: LittleEndian<u16> = bytes[2..].try_into().unwrap();
+        assert_eq!(value.to_native(), 0x7856);
+
+        assert_eq!(
+            BigEndian::<u64>::try_from(bytes),
+            Err(TryFromSliceError::TooShort)
+        );
+        assert_eq!(
+            LittleEndian::<u16>::try_from(bytes),
+            Err(TryFromSliceError::TooLong)
+        );
+        assert!(matches!(
+            BigEndian::<f32>::try_from(&bytes[..3]),
+            Err(TryFromSliceError::TooShort)
+        ));
+    }
+
+    #[test]
+    fn to_native_const() {
+        const FLAGS: u32 = BigEndian::<u32>::new_const(0x8000_0001).to_native_const();
+        const OFFSET: i64 = LittleEndian::<i64>::from_bytes([0xfe; 8]).to_native_const();
+        const LEN: u16 = NativeEndian::<u16>::new_const(12).to_native_const();
+
+        assert_eq!(FLAGS, 0x8000_0001);
+        assert_eq!(FLAGS, BigEndian::new(0x8000_0001u32).to_native());
+        assert_eq!(
+            OFFSET,
+            LittleEndian::<i64>::from_bytes([0xfe; 8]).to_native()
+        );
+        assert_eq!(LEN, 12);
+    }
+
+    #[test]
+    fn from_bits() {
+        let value = BigEndian::new(0x1234_5678u32);
+        assert_eq!(BigEndian::from_bits(value.to_bits()), value);
+        assert_eq!(value.into_bits(), value.to_bits());
+
+        // The bits are stored verbatim, without any conversion.
+        let value = LittleEndian::from_bits(u16::from_ne_bytes([0x34, 0x12]));
+        assert_eq!(value.to_native(), 0x1234);
+
+        let value = BigEndian::new(-1.5f64);
+        assert_eq!(BigEndian::from_bits(value.into_bits()), value);
+    }
+
+    #[test]
+    fn eq_native() {
+        let value = BigEndian::new(12345u64);
+        assert!(value == 12345u64);
+        assert!(12345u64 == value);
+        assert!(value != 1u64);
+        assert!(1u64 != value);
+
+        let value = LittleEndian::new(-2i16);
+        assert_eq!(value, -2);
+        assert_eq!(-2, value);
+
+        assert_eq!(NativeEndian::new(1.5f32), 1.5);
+        assert_ne!(BigEndian::new(f64::NAN), f64::NAN);
+    }
+
+    #[test]
+    fn eq_cross_endian() {
+        let be = BigEndian::new(7u32);
+        let le = LittleEndian::new(7u32);
+        assert!(be == le);
+        assert!(le == be);
+        assert_ne!(be.to_bits(), le.to_bits());
+
+        assert_eq!(NativeEndian::new(7u32), be);
+        assert_ne!(BigEndian::new(8u32), le);
+    }
+
+    #[test]
+    fn bool_round_trip() {
+        for value in [true, false] {
+            let be = BigEndian::new(value);
+            let le = LittleEndian::new(value);
+            assert_eq!(be.to_native(), value);
+            assert_eq!(le.to_native(), value);
+            assert_eq!(be.to_bits(), value);
+            assert_eq!(le.to_bits(), value);
+            assert_eq!(bool::from(be), value);
+            assert_eq!(be, le);
+        }
+    }
+
+    #[test]
+    fn ipv4_addr() {
+        let addr = Ipv4Addr::new(192, 168, 1, 1);
+        let value = BigEndian::<u32>::from(addr);
+        assert_eq!(value.to_bytes(), [192, 168, 1, 1]);
+        assert_eq!(value.to_native(), 0xc0a8_0101);
+        assert_eq!(Ipv4Addr::from(value), addr);
+    }
+
+    #[test]
+    fn to_little_to_big() {
+        let be = BigEndian::new(0x1234_5678u32);
+        let le = be.to_little();
+        assert_eq!(le.to_native(), 0x1234_5678);
+        assert_eq!(le.to_bits(), be.to_bits().swap_bytes());
+        assert_eq!(le.to_big().to_bits(), be.to_bits());
+        assert_eq!(be.to_little().to_big().to_little().to_bits(), le.to_bits());
+    }
+
+    #[test]
+    fn map() {
+        let value = BigEndian::new(0x0102u16).map(|x| x * 2);
+        assert_eq!(value.to_native(), 0x0204);
+        assert_eq!(value.to_bytes(), [0x02, 0x04]);
+
+        let value = LittleEndian::new(0x0102u16).map(|x| x * 2);
+        assert_eq!(value.to_bytes(), [0x04, 0x02]);
+    }
+
+    #[test]
+    fn usize_bytes() {
+        assert_eq!(BigEndian::new(1usize).to_bytes().len(), size_of::<usize>());
+        assert_eq!(
+            LittleEndian::new(-1isize).to_bytes().len(),
+            size_of::<isize>()
+        );
+
+        let value = BigEndian::new(0x0102usize);
+        assert_eq!(BigEndian::<usize>::from_bytes(value.to_bytes()), value);
+        assert_eq!(
+            LittleEndian::<usize>::read_from(&value.to_bytes()),
+            Some(value.swap_bytes().to_little())
+        );
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn usize_bytes_64() {
+        assert_pointer_width!(64);
+        assert_eq!(
+            BigEndian::new(0x0102usize).to_bytes(),
+            [0, 0, 0, 0, 0, 0, 1, 2]
+        );
+        assert_eq!(
+            LittleEndian::new(-2isize).to_bytes(),
+            [0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]
+        );
+        assert_eq!(BigEndian::<usize>::read_from(&[0; 7]), None);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "32")]
+    fn usize_bytes_32() {
+        assert_pointer_width!(32);
+        assert_eq!(BigEndian::new(0x0102usize).to_bytes(), [0, 0, 1, 2]);
+        assert_eq!(
+            LittleEndian::new(-2isize).to_bytes(),
+            [0xfe, 0xff, 0xff, 0xff]
+        );
+        assert_eq!(BigEndian::<usize>::read_from(&[0; 3]), None);
+    }
+
+    #[test]
+    fn from_byte_array() {
+        assert_eq!(BigEndian::<u8>::from([0x12]).to_native(), 0x12);
+        assert_eq!(BigEndian::<u16>::from([0x12, 0x34]).to_native(), 0x1234);
+        assert_eq!(LittleEndian::<u16>::from([0x12, 0x34]).to_native(), 0x3412);
+        assert_eq!(
+            BigEndian::<u32>::from([0x12, 0x34, 0x56, 0x78]).to_native(),
+            0x1234_5678
+        );
+        assert_eq!(
+            LittleEndian::<i64>::from([0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]).to_native(),
+            -2
+        );
+        assert_eq!(BigEndian::<u128>::from(1u128.to_be_bytes()).to_native(), 1);
+        assert_eq!(
+            BigEndian::<f32>::from(1.5f32.to_be_bytes())
+                .to_native()
+                .to_bits(),
+            1.5f32.to_bits()
+        );
+    }
+
+    #[test]
+    fn from_str_radix() {
+        let value = BigEndian::<u32>::from_str_radix("1234abcd", 16).unwrap();
+        assert_eq!(value.to_native(), 0x1234_abcd);
+        assert_eq!(value.to_bytes(), [0x12, 0x34, 0xab, 0xcd]);
+        #[cfg(target_endian = "little")]
+        assert_eq!(value.to_bits(), 0xcdab_3412);
+
+        let value = LittleEndian::<i16>::from_str_radix("-7f", 16).unwrap();
+        assert_eq!(value.to_native(), -0x7f);
+
+        assert!(BigEndian::<u8>::from_str_radix("100", 16).is_err());
+    }
+
+    #[test]
+    fn from_str() {
+        let value = "42".parse::<BigEndian<u16>>().unwrap();
+        assert_eq!(value.to_native(), 42);
+        assert_eq!(value.to_bytes(), [0, 42]);
+        assert_eq!("-42".parse::<LittleEndian<i64>>().unwrap(), -42);
+
+        let err = "4x2".parse::<BigEndian<u16>>().unwrap_err();
+        assert_eq!(err, "4x2".parse::<u16>().unwrap_err());
+        assert!("70000".parse::<BigEndian<u16>>().is_err());
+    }
+
+    #[test]
+    fn min_max_clamp() {
+        let small = BigEndian::new(0x0000_00ffu32);
+        let large = BigEndian::new(0x0100_0000u32);
+        // The stored bits of the larger value are smaller on little endian
+        // targets.
+        assert_eq!(small.max(large), large);
+        assert_eq!(large.max(small), large);
+        assert_eq!(small.min(large), small);
+
+        let lo = LittleEndian::new(10i32);
+        let hi = LittleEndian::new(20i32);
+        assert_eq!(LittleEndian::new(5).clamp(lo, hi), lo);
+        assert_eq!(LittleEndian::new(25).clamp(lo, hi), hi);
+        assert_eq!(LittleEndian::new(15).clamp(lo, hi), 15);
+
+        assert_eq!(BigEndian::new(1.5f64).max(BigEndian::new(-2.0)), 1.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "lo must not be greater than hi")]
+    fn clamp_invalid() {
+        let _ = BigEndian::new(1u8).clamp(BigEndian::new(2), BigEndian::new(1));
+    }
+
+    #[test]
+    fn rotate() {
+        let value = BigEndian::new(0x1234_5678u32);
+        assert_eq!(
+            value.rotate_left(8).to_native(),
+            0x1234_5678u32.rotate_left(8)
+        );
+        assert_eq!(value.rotate_right(4).to_native(), 0x8123_4567);
+        assert_eq!(value.rotate_left(8).to_bytes(), [0x34, 0x56, 0x78, 0x12]);
+
+        let value = LittleEndian::new(-2i16);
+        assert_eq!(value.rotate_right(1).to_native(), (-2i16).rotate_right(1));
+    }
+
+    #[test]
+    fn count_bits() {
+        for native in [0u32, 1, 0x00f0_0000, u32::MAX] {
+            let value = BigEndian::new(native);
+            assert_eq!(value.count_ones(), native.count_ones());
+            assert_eq!(value.count_zeros(), native.count_zeros());
+            assert_eq!(value.leading_zeros(), native.leading_zeros());
+            assert_eq!(value.trailing_zeros(), native.trailing_zeros());
+        }
+
+        let value = LittleEndian::new(-1i8);
+        assert_eq!(value.count_ones(), 8);
+        assert_eq!(LittleEndian::new(0x0100u16).trailing_zeros(), 8);
+    }
+
+    #[test]
+    fn write_hex_bytes() {
+        let mut out = Buf::<16>::new();
+        BigEndian::new(0x1234_abcdu32)
+            .write_hex_bytes(&mut out)
+            .unwrap();
+        assert_eq!(out.as_str(), "12 34 ab cd");
+
+        let mut out = Buf::<16>::new();
+        LittleEndian::new(0x1234_abcdu32)
+            .write_hex_bytes_with(&mut out, ":")
+            .unwrap();
+        assert_eq!(out.as_str(), "cd:ab:34:12");
+
+        let mut out = Buf::<2>::new();
+        BigEndian::new(7u8).write_hex_bytes(&mut out).unwrap();
+        assert_eq!(out.as_str(), "07");
+
+        // "12 34 ab cd" is 11 bytes, so the error of the last write is returned.
+        let mut out = Buf::<10>::new();
+        assert_eq!(
+            BigEndian::new(0x1234_abcdu32).write_hex_bytes(&mut out),
+            Err(fmt::Error)
+        );
+        assert_eq!(out.as_str(), "12 34 ab ");
+
+        let mut out = Buf::<4>::new();
+        assert_eq!(
+            LittleEndian::new(0x1234u16).write_hex_bytes_with(&mut out, " - "),
+            Err(fmt::Error)
+        );
+        assert_eq!(out.as_str(), "34");
+    }
+
+    #[test]
+    fn as_endian_slice() {
+        #[repr(align(4))]
+        struct Aligned([u8; 10]);
+
+        let bytes = Aligned([0, 0, 0, 1, 0, 0, 0, 2, 3, 4]);
+
+        let (values, rest) = BigEndian::<u32>::as_endian_slice(&bytes.0);
+        assert_eq!(values, [BigEndian::new(1), BigEndian::new(2)]);
+        assert_eq!(rest, [3, 4]);
+
+        let (values, rest) = LittleEndian::<u16>::as_endian_slice(&bytes.0[2..]);
+        assert_eq!(values, [0x0100, 0, 0x0200, 0x0403]);
+        assert!(rest.is_empty());
+
+        let (values, rest) = BigEndian::<u32>::as_endian_slice(&bytes.0[1..]);
+        assert!(values.is_empty());
+        assert_eq!(rest, &bytes.0[1..]);
+
+        let (values, rest) = BigEndian::<u32>::as_endian_slice(&bytes.0[..3]);
+        assert!(values.is_empty());
+        assert_eq!(rest, &bytes.0[..3]);
+    }
+
+    #[test]
+    fn wrapping() {
+        let value = BigEndian::from(Wrapping(u32::MAX) + Wrapping(2));
+        assert_eq!(value.to_native(), 1);
+        assert_eq!(Wrapping::from(value), Wrapping(1));
+
+        let value = LittleEndian::new(i8::MIN);
+        assert_eq!(Wrapping::from(value) - Wrapping(1), Wrapping(i8::MAX));
+    }
+
+    #[test]
+    fn signed() {
+        let value = BigEndian::new(-5i32);
+        assert_eq!(value.abs().to_native(), 5);
+        assert_eq!(value.signum(), -1);
+        assert!(value.is_negative());
+        assert!(!value.is_positive());
+
+        let value = LittleEndian::new(0i64);
+        assert_eq!(value.signum(), 0);
+        assert!(!value.is_negative() && !value.is_positive());
+        assert_eq!(LittleEndian::new(7i16).signum(), 1);
+    }
+
+    #[test]
+    fn debug_native() {
+        let value = BigEndian::new(0x1234u16);
+        assert_eq!(format!("{value:?}"), "BigEndian(4660)");
+        assert_eq!(
+            format!("{:?}", LittleEndian::new(-2i32)),
+            "LittleEndian(-2)"
+        );
+
+        // The stored bits are 13330 on little endian targets.
+        #[cfg(target_endian = "little")]
+        assert!(!format!("{value:?}").contains("13330"));
+
+        assert_eq!(
+            format!("{value:#x?}"),
+            format!(
+                "BigEndian {{\n    native: 0x1234,\n    bits: {:#x},\n}}",
+                value.to_bits()
+            )
+        );
+    }
+
+    #[test]
+    fn try_from_narrowing() {
+        let value = BigEndian::<u16>::try_from(BigEndian::new(0x1234u32)).unwrap();
+        assert_eq!(value.to_bytes(), [0x12, 0x34]);
+        assert!(BigEndian::<u16>::try_from(BigEndian::new(0x1_0000u32)).is_err());
+
+        assert_eq!(
+            LittleEndian::<i8>::try_from(LittleEndian::new(-128i64)).unwrap(),
+            -128
+        );
+        assert!(LittleEndian::<i8>::try_from(LittleEndian::new(128i64)).is_err());
+    }
+
+    #[test]
+    fn from_widening() {
+        let value = BigEndian::<u32>::from(BigEndian::new(0x1234u16));
+        assert_eq!(value.to_native(), 0x1234);
+        assert_eq!(value.to_bytes(), [0, 0, 0x12, 0x34]);
+
+        let value = LittleEndian::<i64>::from(LittleEndian::new(-2i8));
+        assert_eq!(value.to_native(), -2);
+        assert_eq!(
+            LittleEndian::<i32>::from(LittleEndian::new(u16::MAX)),
+            0xffff
+        );
+    }
+
+    #[test]
+    fn pow() {
+        assert_eq!(LittleEndian::new(2u32).pow(10).to_native(), 1024);
+        assert_eq!(
+            BigEndian::new(-3i16).pow(3).to_bytes(),
+            (-27i16).to_be_bytes()
+        );
+        assert_eq!(BigEndian::new(7u8).pow(0), 1);
+    }
+
+    #[test]
+    fn reverse_bits() {
+        let value = BigEndian::new(0x0000_00f1u32).reverse_bits();
+        assert_eq!(value.to_native(), 0x0000_00f1u32.reverse_bits());
+        assert_eq!(value.to_native(), 0x8f00_0000);
+        assert_eq!(value.to_bytes(), [0x8f, 0, 0, 0]);
+
+        let value = LittleEndian::new(0b0000_0110u8).reverse_bits();
+        assert_eq!(value.to_native(), 0b0110_0000);
+    }
+
+    #[test]
+    fn to_le_be_bytes() {
+        let value = BigEndian::new(0x1234u16);
+        assert_eq!(value.to_le_bytes(), [0x34, 0x12]);
+        assert_eq!(value.to_be_bytes(), [0x12, 0x34]);
+
+        let value = LittleEndian::new(0x1234u16);
+        assert_eq!(value.to_le_bytes(), [0x34, 0x12]);
+        assert_eq!(value.to_be_bytes(), [0x12, 0x34]);
+
+        assert_eq!(BigEndian::new(1.0f32).to_le_bytes(), 1.0f32.to_le_bytes());
+    }
+
+    #[test]
+    fn sum_product() {
+        let values = [1u16, 2, 0x0100].map(LittleEndian::new);
+        let sum: LittleEndian<u16> = values.iter().sum();
+        assert_eq!(sum.to_native(), 0x0103);
+        assert_eq!(sum.to_bytes(), [0x03, 0x01]);
+        assert_eq!(values.into_iter().sum::<LittleEndian<u16>>(), sum);
+
+        let values = [2u32, 3, 7].map(BigEndian::new);
+        let product: BigEndian<u32> = values.into_iter().product();
+        assert_eq!(product.to_bytes(), [0, 0, 0, 42]);
+        assert_eq!(values.iter().product::<BigEndian<u32>>(), 42);
+        assert_eq!(
+            core::iter::empty::<BigEndian<u32>>().product::<BigEndian<u32>>(),
+            1
+        );
+    }
+
+    #[test]
+    fn range() {
+        let values: Vec<_> = BigEndian::<u32>::range(0..10).collect();
+        assert_eq!(values.len(), 10);
+        assert_eq!(values[3].to_bytes(), [0, 0, 0, 3]);
+        assert_eq!(values[9], 9);
+
+        let mut values = LittleEndian::<i16>::range(-1..1);
+        assert_eq!(values.len(), 2);
+        assert_eq!(values.next_back().unwrap().to_bytes(), [0, 0]);
+        assert_eq!(values.next().unwrap().to_bytes(), [0xff, 0xff]);
+        assert!(BigEndian::<u8>::range(5..5).next().is_none());
+    }
+
+    #[test]
+    fn host_endianness() {
+        assert_eq!(HOST_IS_BIG_ENDIAN, cfg!(target_endian = "big"));
+        assert_eq!(host_is_big_endian(), HOST_IS_BIG_ENDIAN);
+        assert_eq!(HOST_IS_BIG_ENDIAN, u16::from_ne_bytes([1, 0]) == 0x0100);
+    }
+
+    #[test]
+    fn split_join_u16() {
+        let value = BigEndian::new(0x1234_5678u32);
+        let halves = value.split_u16();
+        assert_eq!(halves, [0x1234u16, 0x5678]);
+        assert_eq!(BigEndian::join_u16(halves), value);
+
+        let value = LittleEndian::new(0x1234_5678u32);
+        let halves = value.split_u16();
+        assert_eq!(halves, [0x5678u16, 0x1234]);
+        assert_eq!(LittleEndian::join_u16(halves), value);
+
+        let value = NativeEndian::new(0x1234_5678u32);
+        assert_eq!(NativeEndian::join_u16(value.split_u16()), value);
+    }
+
+    #[test]
+    fn neg() {
+        assert_eq!((-BigEndian::new(5i32)).to_native(), -5);
+        assert_eq!((-LittleEndian::new(-5i8)).to_bytes(), [5]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "overflow")]
+    fn neg_overflow() {
+        let _ = -BigEndian::new(i32::MIN);
+    }
+
+    #[test]
+    fn swap_slice() {
+        let natives = [0x1234_5678u32, 1, u32::MAX, 0];
+
+        let mut values = natives;
+        swap_slice_to_be(&mut values);
+        assert_eq!(values, natives.map(u32::to_be));
+        assert_eq!(values[0].to_ne_bytes(), [0x12, 0x34, 0x56, 0x78]);
+
+        let mut values = natives;
+        swap_slice_to_le(&mut values);
+        assert_eq!(values, natives.map(u32::to_le));
+
+        let mut values = natives;
+        swap_slice_to_order::<Ne, _>(&mut values);
+        assert_eq!(values, natives);
+
+        let mut values = [1.5f64, -2.0];
+        swap_slice_to_be(&mut values);
+        assert_eq!(
+            values.map(f64::to_bits),
+            [1.5f64, -2.0].map(|x| x.to_bits().to_be())
+        );
+    }
+
+    #[test]
+    fn from_native() {
+        assert_eq!(
+            BigEndian::from_native(0x1234_5678u32).to_bits(),
+            BigEndian::new(0x1234_5678u32).to_bits()
+        );
+        assert_eq!(
+            LittleEndian::from_native(-2i64).to_bits(),
+            LittleEndian::new(-2i64).to_bits()
+        );
+    }
+
+    #[test]
+    fn saturating() {
+        let value = BigEndian::from(Saturating(u32::MAX) + Saturating(2));
+        assert_eq!(value.to_native(), u32::MAX);
+        assert_eq!(Saturating::from(value), Saturating(u32::MAX));
+
+        let value = LittleEndian::new(i8::MIN);
+        assert_eq!(Saturating::from(value) - Saturating(1), Saturating(i8::MIN));
+        assert_eq!(LittleEndian::from(Saturating(-5i8)), -5);
+    }
+
+    #[test]
+    fn power_of_two() {
+        assert_eq!(BigEndian::new(5u32).next_power_of_two().to_native(), 8);
+        assert!(BigEndian::new(1024u32).is_power_of_two());
+        assert!(!LittleEndian::new(0u16).is_power_of_two());
+        assert_eq!(LittleEndian::new(200u8).checked_next_power_of_two(), None);
+        assert_eq!(
+            LittleEndian::new(100u8).checked_next_power_of_two(),
+            Some(128.into())
+        );
+    }
+
+    #[test]
+    fn cast_signedness() {
+        let value = BigEndian::new(-1i32);
+        let unsigned = value.cast_unsigned();
+        assert_eq!(unsigned.to_native(), 0xffff_ffff);
+        assert_eq!(unsigned.to_bytes(), value.to_bytes());
+        assert_eq!(unsigned.cast_signed(), value);
+
+        let value = LittleEndian::new(0x8001u16);
+        assert_eq!(value.cast_signed().to_native(), -0x7fff);
+        assert_eq!(value.cast_signed().to_bytes(), [0x01, 0x80]);
+    }
+
+    #[test]
+    fn volatile() {
+        let mut register = BigEndian::new(0u32);
+        let ptr = ptr::addr_of_mut!(register);
+
+        unsafe { BigEndian::write_volatile(ptr, 0x1234_5678) };
+        assert_eq!(unsafe { BigEndian::read_volatile(ptr) }, 0x1234_5678);
+        assert_eq!(register.to_bytes(), [0x12, 0x34, 0x56, 0x78]);
+
+        let register = LittleEndian::new(0xabcdu16);
+        assert_eq!(
+            unsafe { LittleEndian::read_volatile(ptr::addr_of!(register)) },
+            0xabcd
+        );
+    }
+
+    #[test]
+    fn overflowing() {
+        let (value, overflowed) = BigEndian::new(250u8).overflowing_add(BigEndian::new(10));
+        assert_eq!((value.to_native(), overflowed), 250u8.overflowing_add(10));
+
+        let (value, overflowed) = LittleEndian::new(1u32).overflowing_sub(LittleEndian::new(2));
+        assert_eq!((value.to_native(), overflowed), 1u32.overflowing_sub(2));
+        assert_eq!(value.to_bytes(), [0xff; 4]);
+
+        let (value, overflowed) = BigEndian::new(3i16).overflowing_mul(BigEndian::new(4));
+        assert_eq!((value.to_native(), overflowed), (12, false));
+    }
+
+    #[test]
+    fn from_ref() {
+        let natives = [1u32, 0x1234_5678];
+        let values: Vec<BigEndian<u32>> = natives.iter().map(BigEndian::from).collect();
+        assert_eq!(values, natives);
+        assert_eq!(values[1].to_bytes(), [0x12, 0x34, 0x56, 0x78]);
+        assert_eq!(LittleEndian::<i8>::from(&-1).to_native(), -1);
+    }
+
+    #[test]
+    fn byte_len() {
+        const fn buffer<T: Endian<T>, O: Order>() -> usize {
+            Integer::<T, O>::BYTE_LEN
+        }
+        const LEN: usize = buffer::<u32, Be>();
+
+        assert_eq!(BigEndian::<u8>::BYTE_LEN, 1);
+        assert_eq!(LittleEndian::<i16>::BYTE_LEN, 2);
+        assert_eq!(NativeEndian::<f32>::BYTE_LEN, 4);
+        assert_eq!(BigEndian::<u64>::BYTE_LEN, 8);
+        assert_eq!(LittleEndian::<u128>::BYTE_LEN, 16);
+        assert_eq!(BigEndian::<[u16; 3]>::BYTE_LEN, 6);
+        assert_eq!(BigEndian::<usize>::BYTE_LEN, size_of::<usize>());
+        assert_eq!([0u8; LEN].len(), 4);
+    }
+
+    #[test]
+    fn type_name() {
+        assert_eq!(BigEndian::<u32>::TYPE_NAME, "be:u32");
+        assert_eq!(LittleEndian::<i64>::TYPE_NAME, "le:i64");
+        assert_eq!(NativeEndian::<f32>::TYPE_NAME, "ne:f32");
+        assert_eq!(BigEndian::<NonZeroU16>::TYPE_NAME, "be:NonZeroU16");
+    }
+
+    #[test]
+    fn sum_native() {
+        let values = [1u16, 0x0100, 0x1000].map(BigEndian::new);
+        assert_eq!(BigEndian::sum_native(values.into_iter()), 0x1101u16);
+        assert_eq!(BigEndian::sum_native(values.iter().copied().take(0)), 0u16);
+    }
+
+    #[test]
+    fn zero_one() {
+        assert_eq!(BigEndian::<u32>::ZERO.to_native(), 0);
+        assert_eq!(BigEndian::<u32>::ONE.to_native(), 1);
+        assert_eq!(BigEndian::<u32>::ONE.to_bytes(), [0, 0, 0, 1]);
+        assert_eq!(LittleEndian::<i16>::ONE.to_bytes(), [1, 0]);
+        assert_eq!(NativeEndian::<u8>::ZERO, 0);
+    }
+
+    #[test]
+    fn reversed() {
+        // The stored bits of these are ordered differently from their native
+        // values on little endian targets.
+        let mut values = [0x0001u32, 0x0100, 0x00ff, 0x1000].map(BigEndian::new);
+        values.sort_by_key(|value| value.reversed());
+        assert_eq!(
+            values.map(BigEndian::to_native),
+            [0x1000, 0x0100, 0x00ff, 0x0001]
+        );
+
+        let mut values = [-1i16, 2, 0].map(LittleEndian::new);
+        values.sort_by_key(|value| value.reversed());
+        assert_eq!(values.map(LittleEndian::to_native), [2, 0, -1]);
+        assert!(LittleEndian::new(1i16).reversed() < LittleEndian::new(0i16).reversed());
+    }
+
+    #[test]
+    fn min_max_consts() {
+        assert_eq!(BigEndian::<u8>::MAX.to_native(), 255);
+        assert_eq!(BigEndian::<u8>::MIN.to_native(), 0);
+        assert_eq!(BigEndian::<i32>::MIN.to_bytes(), [0x80, 0, 0, 0]);
+        assert_eq!(LittleEndian::<i32>::MIN.to_bytes(), [0, 0, 0, 0x80]);
+        assert_eq!(LittleEndian::<i16>::MAX.to_bytes(), [0xff, 0x7f]);
+        assert_eq!(NativeEndian::<u64>::MAX, u64::MAX);
+    }
+
+    #[test]
+    fn nonzero_try_from_bits() {
+        assert_eq!(BigEndian::<NonZeroU32>::try_from_bits(0), None);
+        assert_eq!(LittleEndian::<NonZeroI16>::try_from_bits(0), None);
+
+        let value = BigEndian::<NonZeroU32>::try_from_bits(0x0100u32.to_be()).unwrap();
+        assert_eq!(value.to_native().get(), 0x0100);
+    }
+
+    #[test]
+    fn bytes_eq() {
+        let value = BigEndian::new(0x1234_5678u32);
+        assert!(value.bytes_eq(&[0x12, 0x34, 0x56, 0x78]));
+        assert!(!value.bytes_eq(&[0x78, 0x56, 0x34, 0x12]));
+        assert!(!value.bytes_eq(&[0x12, 0x34, 0x56]));
+        assert!(!value.bytes_eq(&[0x12, 0x34, 0x56, 0x78, 0x00]));
+        assert!(!value.bytes_eq(&[]));
+        assert!(LittleEndian::new(0x1234u16).bytes_eq(&[0x34, 0x12]));
+    }
+
+    #[test]
+    fn bits() {
+        let native = 0x1234_5678u32;
+        let value = BigEndian::new(native);
+        assert_eq!(value.bits(4..12), (native >> 4) & 0xff);
+        assert_eq!(value.bits(4..12), 0x67);
+        assert_eq!(value.bits(0..32), native);
+        assert_eq!(value.bits(28..32), 0x1);
+        assert_eq!(value.bits(8..8), 0);
+        assert_eq!(value.bits(32..32), 0);
+        assert_eq!(LittleEndian::new(u8::MAX).bits(1..8), 0x7f);
+    }
+
+    #[test]
+    #[should_panic(expected = "bit range out of bounds")]
+    fn bits_out_of_bounds() {
+        let _ = BigEndian::new(0u16).bits(8..17);
+    }
+
+    #[test]
+    fn nth_byte() {
+        let value = BigEndian::new(0x1234_5678u32);
+        let bytes: Vec<_> = (0..4).map(|i| value.nth_byte(i).unwrap()).collect();
+        assert_eq!(bytes, [0x12, 0x34, 0x56, 0x78]);
+        assert_eq!(value.nth_byte(4), None);
+        assert_eq!(LittleEndian::new(0x1234u16).nth_byte(0), Some(0x34));
+    }
+
+    #[test]
+    fn duration() {
+        let duration = duration_from(BigEndian::new(12u64), BigEndian::new(345_000u32));
+        assert_eq!(duration.as_secs(), 12);
+        assert_eq!(duration.subsec_nanos(), 345_000);
+
+        let duration = duration_from(LittleEndian::new(1u64), BigEndian::new(1_500_000_000u32));
+        assert_eq!(duration, Duration::from_millis(2500));
+    }
+
+    #[test]
+    fn to_endian() {
+        assert_eq!(0x1234u16.to_be_wrapped().to_bytes(), [0x12, 0x34]);
+        assert_eq!(0x1234u16.to_le_wrapped().to_bytes(), [0x34, 0x12]);
+        assert_eq!((-1i64).to_be_wrapped(), BigEndian::new(-1i64));
+        assert_eq!(1.5f32.to_le_wrapped().to_bytes(), 1.5f32.to_le_bytes());
+    }
+
+    #[test]
+    fn u128_bytes() {
+        const NATIVE: u128 = 0x0011_2233_4455_6677_8899_aabb_ccdd_eeff;
+        let be_bytes = [
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd,
+            0xee, 0xff,
+        ];
+        let mut le_bytes = be_bytes;
+        le_bytes.reverse();
+
+        let be = BigEndian::new(NATIVE);
+        let le = LittleEndian::new(NATIVE);
+        assert_eq!(be.to_bytes(), be_bytes);
+        assert_eq!(le.to_bytes(), le_bytes);
+        assert_eq!(be.to_be_bytes(), be_bytes);
+        assert_eq!(le.to_be_bytes(), be_bytes);
+        assert_eq!(be.to_le_bytes(), le_bytes);
+        assert_eq!(BigEndian::<u128>::from_be_bytes(be_bytes), be);
+        assert_eq!(BigEndian::<u128>::from_le_bytes(le_bytes), be);
+        assert_eq!(LittleEndian::<u128>::from_be_bytes(be_bytes), le);
+        assert_eq!(be.to_bits().to_ne_bytes(), be_bytes);
+
+        let mut buffer = [0u8; 33];
+        be.write_to(&mut buffer).unwrap();
+        le.write_to(&mut buffer[16..]).unwrap();
+        assert_eq!(buffer[..16], be_bytes);
+        assert_eq!(buffer[16..32], le_bytes);
+
+        let (value, rest) = BigEndian::<u128>::read_from_prefix(&buffer).unwrap();
+        assert_eq!(value.to_native(), NATIVE);
+        assert_eq!(LittleEndian::<u128>::read_from(rest), Some(le));
+
+        let value = LittleEndian::new(-2i128);
+        assert_eq!(value.to_bytes()[0], 0xfe);
+        assert_eq!(value.to_bytes()[1..], [0xff; 15]);
+    }
+
+    #[test]
+    fn stable_hashing() {
+        // Records the bytes that are fed into it.
+        #[derive(Default)]
+        struct Recorder(Vec<u8>);
+
+        impl Hasher for Recorder {
+            fn finish(&self) -> u64 {
+                0
+            }
+
+            fn write(&mut self, bytes: &[u8]) {
+                self.0.extend_from_slice(bytes);
+            }
+        }
+
+        let be = BigEndian::new(0x1234_5678u32);
+        let le = LittleEndian::new(0x1234_5678u32);
+        assert_eq!(be.stable_bytes(), [0x12, 0x34, 0x56, 0x78]);
+        assert_eq!(le.stable_bytes(), be.stable_bytes());
+
+        let mut state = Recorder::default();
+        state.write(&le.stable_bytes());
+        assert_eq!(state.0, [0x12, 0x34, 0x56, 0x78]);
+
+        let mut native = DefaultHasher::new();
+        0x1234_5678u32.hash(&mut native);
+        let mut wrapped = DefaultHasher::new();
+        hash_native(&be, &mut wrapped);
+        assert_eq!(wrapped.finish(), native.finish());
+    }
+
+    #[test]
+    fn ipv6_addr() {
+        let addr = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
+        let value = BigEndian::<u128>::from(addr);
+        assert_eq!(value.to_be_bytes(), addr.octets());
+        assert_eq!(value.to_bytes(), addr.octets());
+        assert_eq!(value.to_native(), 0x2001_0db8_0000_0000_0000_0000_0000_0001);
+        assert_eq!(Ipv6Addr::from(value), addr);
     }
 }
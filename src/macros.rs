@@ -0,0 +1,196 @@
+/// Defines a struct whose fields are stored in the given byte orders, along
+/// with methods to read it from and write it to bytes.
+///
+/// Each field is annotated with `#[be]`, `#[le]` or `#[ne]`, which wraps its
+/// type in [`BigEndian`](crate::BigEndian), [`LittleEndian`](crate::LittleEndian)
+/// or [`NativeEndian`](crate::NativeEndian) respectively. The fields are laid
+/// out in the bytes in the order of declaration, without any padding.
+///
+/// The generated struct has the following items:
+///
+/// * `SIZE`: the number of bytes of the encoded struct.
+/// * `from_bytes` and `to_bytes`: conversions from and to `[u8; SIZE]`.
+/// * `read_from` and `read_from_prefix`: reads the struct from the first bytes
+///   of a slice, same as the ones of the endian types.
+///
+/// ```
+/// byte_endian::endian_struct! {
+///     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///     pub struct Header {
+///         #[be] pub magic: u32,
+///         #[le] pub length: u16,
+///     }
+/// }
+///
+/// let header = Header::read_from(&[0xca, 0xfe, 0xba, 0xbe, 0x10, 0x00]).unwrap();
+/// assert_eq!(header.magic, 0xcafe_babe);
+/// assert_eq!(header.length, 0x10);
+/// assert_eq!(Header::SIZE, 6);
+/// ```
+#[macro_export]
+macro_rules! endian_struct {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $(#[$order:ident] $field_vis:vis $field:ident: $field_type:ty),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $($field_vis $field: $crate::endian_struct!(@type $order $field_type)),+
+        }
+
+        impl $name {
+            /// The number of bytes of the encoded struct.
+            pub const SIZE: usize = 0 $(+ ::core::mem::size_of::<$field_type>())+;
+
+            /// Creates a new value from `bytes`, in which the fields are laid out
+            /// in the order of declaration.
+            #[must_use]
+            pub fn from_bytes(bytes: [u8; Self::SIZE]) -> Self {
+                let Some((value, _)) = Self::read_from_prefix(&bytes) else {
+                    unreachable!()
+                };
+                value
+            }
+
+            /// Returns the bytes of `self`, in which the fields are laid out in
+            /// the order of declaration.
+            #[must_use]
+            #[allow(unused_assignments, clippy::wrong_self_convention)]
+            pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+                let mut bytes = [0; Self::SIZE];
+                let mut rest = &mut bytes[..];
+                $(
+                    let (head, tail) = rest.split_at_mut(::core::mem::size_of::<$field_type>());
+                    head.copy_from_slice(&self.$field.to_bytes());
+                    rest = tail;
+                )+
+                bytes
+            }
+
+            /// Reads a value from the first bytes of `bytes`.
+            ///
+            /// Returns `None` if `bytes` is too short.
+            #[must_use]
+            pub fn read_from(bytes: &[u8]) -> Option<Self> {
+                Self::read_from_prefix(bytes).map(|(value, _)| value)
+            }
+
+            /// Same as `read_from`, but also returns the remaining bytes.
+            #[must_use]
+            pub fn read_from_prefix(bytes: &[u8]) -> Option<(Self, &[u8])> {
+                let rest = bytes;
+                $(
+                    let ($field, rest) =
+                        <$crate::endian_struct!(@type $order $field_type)>::read_from_prefix(rest)?;
+                )+
+                Some((Self { $($field),+ }, rest))
+            }
+        }
+    };
+
+    (@type be $field_type:ty) => { $crate::BigEndian<$field_type> };
+    (@type le $field_type:ty) => { $crate::LittleEndian<$field_type> };
+    (@type ne $field_type:ty) => { $crate::NativeEndian<$field_type> };
+}
+
+/// Fails to compile if the target’s pointer width, and so the size of `usize`
+/// and `isize`, is not the given number of bits.
+///
+/// This is useful for code that stores `usize` or `isize` values in a format
+/// that expects a specific width.
+///
+/// ```
+/// # #[cfg(target_pointer_width = "64")]
+/// byte_endian::assert_pointer_width!(64);
+/// ```
+#[macro_export]
+macro_rules! assert_pointer_width {
+    ($bits:literal) => {
+        const _: () = ::core::assert!(
+            usize::BITS == $bits,
+            ::core::concat!("expected a ", $bits, "-bit target")
+        );
+    };
+}
+
+/// Creates an array of [`BigEndian`](crate::BigEndian) integers from native
+/// values, which can be used in `const` and `static` items.
+///
+/// ```
+/// use byte_endian::{be_array, BigEndian};
+///
+/// static TABLE: [BigEndian<u32>; 3] = be_array![u32; 0x1, 0x2, 0x3];
+/// assert_eq!(TABLE[2].to_bytes(), [0, 0, 0, 0x3]);
+/// ```
+#[macro_export]
+macro_rules! be_array {
+    ($int_type:ty; $($value:expr),* $(,)?) => {
+        [$($crate::BigEndian::<$int_type>::new_const($value)),*]
+    };
+}
+
+/// Creates an array of [`LittleEndian`](crate::LittleEndian) integers from
+/// native values, which can be used in `const` and `static` items.
+///
+/// ```
+/// use byte_endian::{le_array, LittleEndian};
+///
+/// static TABLE: [LittleEndian<u16>; 2] = le_array![u16; 0x1234, 0x5678];
+/// assert_eq!(TABLE[0].to_bytes(), [0x34, 0x12]);
+/// ```
+#[macro_export]
+macro_rules! le_array {
+    ($int_type:ty; $($value:expr),* $(,)?) => {
+        [$($crate::LittleEndian::<$int_type>::new_const($value)),*]
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{BigEndian, LittleEndian, NativeEndian};
+
+    endian_struct! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        struct Header {
+            #[be] magic: u32,
+            #[le] length: u16,
+            #[ne] flags: u8,
+        }
+    }
+
+    #[test]
+    fn parse() {
+        const BYTES: [u8; 8] = [0xca, 0xfe, 0xba, 0xbe, 0x34, 0x12, 0x01, 0xff];
+
+        let (header, rest) = Header::read_from_prefix(&BYTES).unwrap();
+        assert_eq!(rest, [0xff]);
+        assert_eq!(
+            header,
+            Header {
+                magic: BigEndian::new(0xcafe_babe),
+                length: LittleEndian::new(0x1234),
+                flags: NativeEndian::new(1),
+            }
+        );
+
+        assert_eq!(Header::SIZE, 7);
+        assert_eq!(header.to_bytes(), BYTES[..7]);
+        assert_eq!(Header::from_bytes(header.to_bytes()), header);
+        assert_eq!(Header::read_from(&BYTES[..6]), None);
+    }
+
+    #[test]
+    fn arrays() {
+        static BE: [BigEndian<u32>; 3] = be_array![u32; 0x1, 0x2, 0xcafe_babe];
+        static LE: [LittleEndian<i16>; 2] = le_array![i16; -1, 0x0102,];
+        const EMPTY: [BigEndian<u8>; 0] = be_array![u8;];
+
+        assert_eq!(BE.map(BigEndian::to_native), [0x1, 0x2, 0xcafe_babe]);
+        assert_eq!(BE[2].to_bytes(), [0xca, 0xfe, 0xba, 0xbe]);
+        assert_eq!(LE.map(LittleEndian::to_native), [-1, 0x0102]);
+        assert_eq!(LE[1].to_bytes(), [0x02, 0x01]);
+        assert!(EMPTY.is_empty());
+    }
+}
@@ -0,0 +1,40 @@
+//! Implements the identity traits of `num-traits` for the endian types.
+
+use ::num_traits::{One, Zero};
+
+use crate::{Endian, Integer, Order};
+
+impl<T: Endian<T> + Zero, O: Order> Zero for Integer<T, O> {
+    #[inline]
+    fn zero() -> Self {
+        Self::new(T::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.to_native().is_zero()
+    }
+}
+
+impl<T: Endian<T> + One, O: Order> One for Integer<T, O> {
+    #[inline]
+    fn one() -> Self {
+        Self::new(T::one())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ::num_traits::{One, Zero};
+
+    use crate::{BigEndian, LittleEndian};
+
+    #[test]
+    fn identities() {
+        assert_eq!(<BigEndian<u32> as Zero>::zero(), BigEndian::<u32>::ZERO);
+        assert_eq!(<LittleEndian<i64> as One>::one(), LittleEndian::<i64>::ONE);
+        assert!(BigEndian::new(0u16).is_zero());
+        assert!(!BigEndian::new(0x0100u16).is_zero());
+        assert_eq!(<BigEndian<f32> as One>::one(), 1.0);
+    }
+}
@@ -0,0 +1,121 @@
+//! An adapter for using the endian types as keys that are ordered by their
+//! native values.
+
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+use crate::{Endian, Integer, Order};
+
+/// Compares, orders and hashes the wrapped endian value by its native value,
+/// e.g. for keys of a `BTreeMap` or `HashMap`.
+///
+/// The endian types already compare their native values, but this makes the
+/// intent explicit at the use site. Note that it cannot implement
+/// [`Borrow<T>`](core::borrow::Borrow), as the native value is not stored and
+/// so cannot be borrowed. Instead, lookups are done with keys that are created
+/// from native values with [`OrderedNative::from_native`].
+#[derive(Default, Debug, Copy, Clone)]
+#[repr(transparent)]
+pub struct OrderedNative<W>(pub W);
+
+impl<T: Endian<T>, O: Order> OrderedNative<Integer<T, O>> {
+    /// Creates a key from `value` in the target’s endianness.
+    #[inline]
+    #[must_use]
+    pub fn from_native(value: T) -> Self {
+        Self(Integer::new(value))
+    }
+
+    /// Returns the native value of the wrapped endian value.
+    #[inline]
+    #[must_use]
+    pub fn to_native(self) -> T {
+        self.0.to_native()
+    }
+}
+
+impl<W> OrderedNative<W> {
+    /// Returns the wrapped endian value.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> W {
+        self.0
+    }
+}
+
+impl<T: Endian<T>, O: Order> From<Integer<T, O>> for OrderedNative<Integer<T, O>> {
+    #[inline]
+    fn from(value: Integer<T, O>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: Endian<T> + PartialEq, O: Order> PartialEq for OrderedNative<Integer<T, O>> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_native() == other.0.to_native()
+    }
+}
+
+impl<T: Endian<T> + Eq, O: Order> Eq for OrderedNative<Integer<T, O>> {}
+
+impl<T: Endian<T> + PartialOrd, O: Order> PartialOrd for OrderedNative<Integer<T, O>> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.to_native().partial_cmp(&other.0.to_native())
+    }
+}
+
+impl<T: Endian<T> + Ord, O: Order> Ord for OrderedNative<Integer<T, O>> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.to_native().cmp(&other.0.to_native())
+    }
+}
+
+impl<T: Endian<T> + Hash, O: Order> Hash for OrderedNative<Integer<T, O>> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_native().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::*;
+    use crate::{BigEndian, LittleEndian};
+    use std::collections::BTreeMap;
+    use std::vec::Vec;
+
+    #[test]
+    fn btree_map() {
+        let mut map = BTreeMap::new();
+        // The stored bits of these are ordered differently from their native
+        // values on little endian targets.
+        for native in [0x0100u32, 0x0001, 0x00ff, 0x1000] {
+            map.insert(OrderedNative(BigEndian::new(native)), native);
+        }
+
+        assert_eq!(map.get(&OrderedNative::from_native(0x00ff)), Some(&0x00ff));
+        assert_eq!(map.get(&OrderedNative::from_native(0x0002)), None);
+
+        let keys: Vec<u32> = map.keys().map(|key| key.to_native()).collect();
+        assert_eq!(keys, [0x0001, 0x00ff, 0x0100, 0x1000]);
+
+        let range: Vec<u32> = map
+            .range(OrderedNative::from_native(0x00ff)..OrderedNative::from_native(0x1000))
+            .map(|(_, &native)| native)
+            .collect();
+        assert_eq!(range, [0x00ff, 0x0100]);
+    }
+
+    #[test]
+    fn eq() {
+        let a = OrderedNative(LittleEndian::new(-1i16));
+        assert_eq!(a, OrderedNative::from_native(-1));
+        assert!(a < OrderedNative::from_native(0));
+        assert_eq!(a.into_inner(), LittleEndian::new(-1));
+    }
+}
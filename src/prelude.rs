@@ -0,0 +1,22 @@
+//! Re-exports the commonly used types and traits, to be glob imported with
+//! `use byte_endian::prelude::*;`.
+
+pub use crate::{BigEndian, Endian, LittleEndian, NativeEndian, Order, ToEndian};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn encode<O: Order, T: Endian<T>>(value: T) -> crate::Integer<T, O> {
+        crate::Integer::new(value)
+    }
+
+    #[test]
+    fn glob_import() {
+        let be: BigEndian<u16> = 0x1234.to_be_wrapped();
+        let le: LittleEndian<u16> = 0x1234.to_le_wrapped();
+        let ne: NativeEndian<u16> = encode(0x1234);
+        assert_eq!(be, le);
+        assert_eq!(be, ne);
+    }
+}
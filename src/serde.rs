@@ -0,0 +1,126 @@
+//! Serializes the endian types as their native value, so that human readable
+//! formats show the actual number rather than the byte swapped one.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use ::serde::de::{self, SeqAccess, Visitor};
+use ::serde::ser::SerializeTuple;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Endian, Integer, Order};
+
+impl<T: Endian<T> + Serialize, O: Order> Serialize for Integer<T, O> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_native().serialize(serializer)
+    }
+}
+
+impl<'de, T: Endian<T> + Deserialize<'de>, O: Order> Deserialize<'de> for Integer<T, O> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self::new)
+    }
+}
+
+/// Serializes the wrapped endian type as its stored bytes rather than its
+/// native value.
+///
+/// The bytes are serialized as a tuple, so that formats with a fixed layout
+/// (e.g. bincode) write them as is, without a length prefix.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RawBytes<W>(pub W);
+
+impl<W: AsRef<[u8]>> Serialize for RawBytes<W> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let bytes = self.0.as_ref();
+        let mut tuple = serializer.serialize_tuple(bytes.len())?;
+        for byte in bytes {
+            tuple.serialize_element(byte)?;
+        }
+        tuple.end()
+    }
+}
+
+impl<'de, W: Default + AsMut<[u8]>> Deserialize<'de> for RawBytes<W> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BytesVisitor<W>(PhantomData<W>);
+
+        impl<'de, W: Default + AsMut<[u8]>> Visitor<'de> for BytesVisitor<W> {
+            type Value = W;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{} bytes", W::default().as_mut().len())
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<W, A::Error> {
+                let mut value = W::default();
+                for (i, byte) in value.as_mut().iter_mut().enumerate() {
+                    *byte = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                }
+                Ok(value)
+            }
+        }
+
+        let len = W::default().as_mut().len();
+        deserializer
+            .deserialize_tuple(len, BytesVisitor(PhantomData))
+            .map(RawBytes)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::RawBytes;
+    use crate::{BigEndian, LittleEndian};
+
+    #[test]
+    fn round_trip() {
+        let value = BigEndian::new(0x1234u32);
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, "4660");
+        assert_eq!(
+            serde_json::from_str::<BigEndian<u32>>(&json).unwrap(),
+            value
+        );
+
+        let values = (LittleEndian::new(-5i16), BigEndian::new(1.5f64));
+        let json = serde_json::to_string(&values).unwrap();
+        assert_eq!(json, "[-5,1.5]");
+        assert_eq!(
+            serde_json::from_str::<(LittleEndian<i16>, BigEndian<f64>)>(&json).unwrap(),
+            values
+        );
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert!(serde_json::from_str::<BigEndian<u8>>("256").is_err());
+    }
+
+    #[test]
+    fn raw_bytes() {
+        let value = RawBytes(BigEndian::new(0x1234u32));
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bytes, [0, 0, 0x12, 0x34]);
+        assert_eq!(
+            bincode::deserialize::<RawBytes<BigEndian<u32>>>(&bytes).unwrap(),
+            value
+        );
+
+        let value = RawBytes(LittleEndian::new(0x1234u32));
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bytes, [0x34, 0x12, 0, 0]);
+        assert_eq!(
+            bincode::deserialize::<RawBytes<LittleEndian<u32>>>(&bytes).unwrap(),
+            value
+        );
+
+        let json = serde_json::to_string(&RawBytes(BigEndian::new(0x1234u16))).unwrap();
+        assert_eq!(json, "[18,52]");
+        assert!(serde_json::from_str::<RawBytes<BigEndian<u16>>>("[18]").is_err());
+    }
+}
@@ -0,0 +1,78 @@
+//! Reading several values, e.g. the fields of a header, from consecutive bytes.
+
+/// Types that can be read from the first bytes of a slice.
+///
+/// This is implemented by the endian types of the primitive integers and
+/// floats, and by tuples of up to 8 of these, which read their elements in
+/// order.
+pub trait ReadFromPrefix: Sized {
+    /// Reads a value from the first bytes of `bytes` and returns it along with
+    /// the remaining bytes.
+    ///
+    /// Returns `None` if `bytes` is too short.
+    fn read_from_prefix(bytes: &[u8]) -> Option<(Self, &[u8])>;
+}
+
+/// Reads a tuple of values from the first bytes of `bytes`, in order, and
+/// returns it along with the remaining bytes.
+///
+/// Returns `None` if `bytes` is too short.
+///
+/// ```
+/// use byte_endian::{read_tuple, BigEndian, LittleEndian};
+///
+/// let bytes = [0x12, 0x34, 0x12, 0x34, 0xff];
+/// let ((a, b), rest) = read_tuple::<(BigEndian<u16>, LittleEndian<u16>)>(&bytes).unwrap();
+/// assert_eq!(a, 0x1234);
+/// assert_eq!(b, 0x3412);
+/// assert_eq!(rest, [0xff]);
+/// ```
+#[inline]
+#[must_use]
+pub fn read_tuple<T: ReadFromPrefix>(bytes: &[u8]) -> Option<(T, &[u8])> {
+    T::read_from_prefix(bytes)
+}
+
+macro_rules! impl_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: ReadFromPrefix),+> ReadFromPrefix for ($($name,)+) {
+            #[inline]
+            #[allow(non_snake_case)]
+            fn read_from_prefix(bytes: &[u8]) -> Option<(Self, &[u8])> {
+                let rest = bytes;
+                $(let ($name, rest) = $name::read_from_prefix(rest)?;)+
+                Some((($($name,)+), rest))
+            }
+        }
+    };
+}
+
+impl_tuple!(A);
+impl_tuple!(A, B);
+impl_tuple!(A, B, C);
+impl_tuple!(A, B, C, D);
+impl_tuple!(A, B, C, D, E);
+impl_tuple!(A, B, C, D, E, F);
+impl_tuple!(A, B, C, D, E, F, G);
+impl_tuple!(A, B, C, D, E, F, G, H);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{BigEndian, LittleEndian};
+
+    #[test]
+    fn mixed() {
+        let bytes = [0x12, 0x34, 0x12, 0x34, 0xde, 0xad, 0xbe, 0xef, 0x01];
+
+        let ((a, b, c), rest) =
+            read_tuple::<(BigEndian<u16>, LittleEndian<u16>, BigEndian<u32>)>(&bytes).unwrap();
+        assert_eq!(a.to_native(), 0x1234);
+        assert_eq!(b.to_native(), 0x3412);
+        assert_eq!(c.to_native(), 0xdead_beef);
+        assert_eq!(rest, [0x01]);
+
+        assert!(read_tuple::<(BigEndian<u64>, BigEndian<u8>)>(&bytes).is_some());
+        assert!(read_tuple::<(BigEndian<u64>, BigEndian<u8>)>(&bytes[..8]).is_none());
+    }
+}
@@ -0,0 +1,34 @@
+//! `Vec<BigEndian<T>>` cannot implement `FromIterator<T>` due to the orphan
+//! rules, so the endian types provide a helper for collecting native values.
+
+use alloc::vec::Vec;
+
+use crate::{Endian, Integer, Order};
+
+impl<T: Endian<T>, O: Order> Integer<T, O> {
+    /// Converts each of the native values yielded by `iter` and collects them
+    /// into a vector.
+    #[inline]
+    pub fn collect_vec<I: IntoIterator<Item = T>>(iter: I) -> Vec<Self> {
+        iter.into_iter().map(Self::new).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{BigEndian, LittleEndian};
+
+    #[test]
+    fn collect_vec() {
+        let values = BigEndian::<u16>::collect_vec(0x0100..0x0104);
+        assert_eq!(values.len(), 4);
+        for (value, low) in values.into_iter().zip(0..) {
+            assert_eq!(value.to_bytes(), [0x01, low]);
+        }
+
+        let values = LittleEndian::collect_vec([1u32, 2, 3]);
+        assert_eq!(values[2].to_bytes(), [3, 0, 0, 0]);
+
+        assert!(BigEndian::<u64>::collect_vec([]).is_empty());
+    }
+}
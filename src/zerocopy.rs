@@ -0,0 +1,40 @@
+//! Tests for the `zerocopy` trait implementations, which are derived on the
+//! endian types themselves.
+
+use ::zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout};
+
+use crate::{BigEndian, LittleEndian};
+
+#[derive(FromBytes, IntoBytes, Immutable, KnownLayout)]
+#[repr(C)]
+struct Header {
+    magic: BigEndian<u32>,
+    version: LittleEndian<u16>,
+    length: BigEndian<u16>,
+}
+
+#[repr(C, align(4))]
+struct Aligned([u8; 8]);
+
+#[test]
+fn parse_header() {
+    let bytes = Aligned([0xca, 0xfe, 0xba, 0xbe, 0x02, 0x00, 0x01, 0x00]);
+
+    let header = Header::ref_from_bytes(&bytes.0).unwrap();
+    assert_eq!(header.magic.to_native(), 0xcafe_babe);
+    assert_eq!(header.version.to_native(), 2);
+    assert_eq!(header.length.to_native(), 256);
+
+    // No copies are made, so the header points straight into the buffer.
+    assert!(core::ptr::eq(header.as_bytes(), &bytes.0));
+}
+
+#[test]
+fn read_from_prefix() {
+    let bytes = [0u8, 0, 0, 0, 0, 0, 0, 0x2a, 0xff];
+
+    let (value, rest) = <BigEndian<u64> as FromBytes>::read_from_prefix(&bytes).unwrap();
+    assert_eq!(value.to_native(), 0x2a);
+    assert_eq!(rest, [0xff]);
+    assert_eq!(value.as_bytes(), &bytes[..8]);
+}